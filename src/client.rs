@@ -1,20 +1,29 @@
 use crate::defs;
-use crate::error::{Error, Result};
+use crate::error::{Error, Kind, Result};
+use crate::id::{CommentId, ContributorId, FilmId, ListId, LogEntryId, MemberId};
+use crate::paginate::Paginator;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::token_store::TokenStore;
 
 use http_body_util::{BodyExt, Full};
-use hyper::body::Bytes;
+use hyper::body::{Bytes, Incoming};
 use hyper::{
     body::Buf,
-    header::{self, HeaderValue},
-    Method, Request,
+    header::{self, HeaderMap, HeaderName, HeaderValue},
+    Method, Request, Response,
 };
 use hyper_tls::HttpsConnector;
-use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::connect::{Connect, HttpConnector};
 use hyper_util::client::legacy::Client as HttpClient;
 use serde::{de::DeserializeOwned, Serialize};
 use url::Url;
 
 use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 /// API key/secret pair.
 ///
@@ -66,35 +75,72 @@ impl ApiKeyPair {
 /// * without a token (no API calls will be authenticated; calls that require
 ///   authentication will fail).
 ///
+/// An expired access token can be renewed with `refresh_token`. If auto refresh is enabled, the
+/// client renews the token by itself before a request when the token is expired, or after a
+/// request was rejected as unauthorized.
+///
+/// A [token store](trait.TokenStore.html) can be attached to the client to persist the token
+/// across client instances and processes.
+///
+/// Use a [ClientBuilder](struct.ClientBuilder.html) to configure e.g. the base url, timeouts,
+/// retries, rate limiting or the HTTP connector of the client.
+///
+/// Cloning a client is cheap. Clones share the token, the connection pool, the rate limiter and
+/// cached responses.
+///
 /// **Note**: Not all APIs are implemented. Feel free to contribute implementation for missing
 /// endpoints. The implementation is usually very straight forward.
+#[derive(Clone)]
 pub struct Client {
     api_key_pair: ApiKeyPair,
-    token: Option<defs::AccessToken>,
-    http_client: HttpClient<HttpsConnector<HttpConnector>, Full<Bytes>>,
+    base_url: Url,
+    token: Arc<RwLock<Option<defs::AccessToken>>>,
+    auto_refresh: bool,
+    refresh_lock: Arc<tokio::sync::Mutex<()>>,
+    token_store: Option<Arc<dyn TokenStore>>,
+    default_headers: HeaderMap,
+    timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    pronouns: Arc<tokio::sync::OnceCell<defs::PronounsResponse>>,
+    http_client: Arc<dyn SendRequest>,
 }
 
 impl Client {
-    const API_BASE_URL: &'static str = "https://api.letterboxd.com/api/v0/";
+    /// Base url of the Letterboxd API used by default.
+    pub const API_BASE_URL: &'static str = "https://api.letterboxd.com/api/v0/";
 
     /// Creates a new client without authentication.
     pub fn new(api_key_pair: ApiKeyPair) -> Self {
-        Self {
-            api_key_pair,
-            token: None,
-            http_client: http_client(),
-        }
+        Self::builder(api_key_pair)
+            .build()
+            .expect("invalid default client configuration")
+    }
+
+    /// Creates a builder to configure a new client.
+    pub fn builder(api_key_pair: ApiKeyPair) -> ClientBuilder {
+        ClientBuilder::new(api_key_pair)
     }
 
     /// Crates a new client from a given token.
     ///
     /// It is not checked that the token is valid.
     pub fn with_token(api_key_pair: ApiKeyPair, token: defs::AccessToken) -> Self {
-        Self {
-            api_key_pair,
-            token: Some(token),
-            http_client: http_client(),
-        }
+        Self::builder(api_key_pair)
+            .token(token)
+            .build()
+            .expect("invalid default client configuration")
+    }
+
+    /// Creates a new client with the given token store.
+    ///
+    /// The token is loaded from the store. If the store is empty, the client is not
+    /// authenticated.
+    pub fn with_token_store(
+        api_key_pair: ApiKeyPair,
+        token_store: impl TokenStore + 'static,
+    ) -> Result<Self> {
+        Self::builder(api_key_pair).token_store(token_store).build()
     }
 
     /// Authenticates and creates a new client from given username/password.
@@ -103,57 +149,145 @@ impl Client {
         username: &str,
         password: &str,
     ) -> Result<Self> {
-        let content_type = HeaderValue::from_static("application/x-www-form-urlencoded");
-
-        #[derive(Debug, Serialize)]
-        struct AuthRequest<'a> {
-            grant_type: &'static str,
-            username: &'a str,
-            password: &'a str,
-        }
-
-        let request = AuthRequest {
-            grant_type: "password",
-            username,
-            password,
-        };
-        let body = serde_url_params::to_vec(&request)?;
-
-        let mut client = Self::new(api_key_pair);
-        let buf = client
-            .request_bytes::<()>(
-                Method::POST,
-                "auth/token",
-                None,
-                Some(content_type),
-                Some(body),
-            )
-            .await?;
-        client.set_token(Some(serde_json::from_reader(&mut buf.reader())?));
-        Ok(client)
+        Self::builder(api_key_pair)
+            .authenticate(username, password)
+            .await
     }
 
     /// Returns if the client has a token.
     ///
     /// This method does *not* check that the token is valid.
     pub fn is_authenticated(&self) -> bool {
-        self.token.is_some()
+        self.token.read().expect("poisoned token lock").is_some()
     }
 
     /// Returns the token used for authentication.
-    pub fn token(&self) -> Option<&defs::AccessToken> {
-        self.token.as_ref()
+    pub fn token(&self) -> Option<defs::AccessToken> {
+        self.token.read().expect("poisoned token lock").clone()
     }
 
     /// Sets a new token which will be used for authentication.
     ///
-    /// Setting `None` disables authentication.
-    pub fn set_token(&mut self, token: Option<defs::AccessToken>) {
-        self.token = token;
+    /// Setting `None` disables authentication. If the client has a token store, the token is
+    /// saved to the store, or the store is cleared when setting `None`.
+    ///
+    /// The token is used by the client even if the store fails, in which case the error of the
+    /// store is returned.
+    pub fn set_token(&self, token: Option<defs::AccessToken>) -> Result<()> {
+        self.replace_token(token.clone());
+        if let Some(token_store) = self.token_store.as_ref() {
+            match token {
+                Some(token) => token_store.save(&token)?,
+                None => token_store.clear()?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Attaches a token store to the client.
+    ///
+    /// If the client has a token, it is saved to the store. Otherwise, the token is loaded from
+    /// the store.
+    pub fn set_token_store(&mut self, token_store: impl TokenStore + 'static) -> Result<()> {
+        self.attach_token_store(Arc::new(token_store))
+    }
+
+    /// Enables or disables the automatic renewal of the access token.
+    ///
+    /// When enabled, the token is refreshed before a request if it is expired, and a request is
+    /// repeated once with a refreshed token if it was rejected with `401 Unauthorized`. Disabled
+    /// by default.
+    pub fn set_auto_refresh(&mut self, auto_refresh: bool) {
+        self.auto_refresh = auto_refresh;
+    }
+
+    /// Requests a new access token using the refresh token of the current token.
+    ///
+    /// The new token replaces the current one. Fails if the client has no token.
+    pub async fn refresh_token(&self) -> Result<()> {
+        let _guard = self.refresh_lock.lock().await;
+        self.request_refreshed_token().await
     }
 
     // API endpoints
 
+    // auth
+
+    /// Request a link via email to reset the password for a member’s account.
+    pub async fn request_password_reset(&self, email_address: &str) -> Result<()> {
+        let request = defs::ForgottenPasswordRequest { email_address };
+        self.post_without_response("auth/forgotten-password", &request)
+            .await
+    }
+
+    /// Check whether a username is available to register.
+    ///
+    /// Usernames must be between 2 and 15 characters long and may only contain upper or
+    /// lowercase letters, numbers or the underscore (_) character.
+    pub async fn check_username(&self, username: &str) -> Result<defs::UsernameCheckResult> {
+        let request = defs::UsernameCheckRequest { username };
+        let resp: defs::UsernameCheckResponse =
+            self.get_with_query("auth/username-check", &request).await?;
+        Ok(resp.result)
+    }
+
+    // comment
+
+    /// Update the message portion of a comment by ID.
+    ///
+    /// Errors like an expired edit window are returned as messages in the response.
+    pub async fn update_comment(
+        &self,
+        id: &CommentId,
+        request: &defs::CommentUpdateRequest,
+    ) -> Result<defs::CommentUpdateResponse> {
+        self.patch(&format!("comment/{}", id), request).await
+    }
+
+    /// Delete a comment by ID.
+    pub async fn delete_comment(&self, id: &CommentId) -> Result<()> {
+        self.delete(&format!("comment/{}", id)).await
+    }
+
+    /// Report a comment by ID.
+    pub async fn report_comment(
+        &self,
+        id: &CommentId,
+        request: &defs::ReportCommentRequest,
+    ) -> Result<()> {
+        self.post_without_response(&format!("comment/{}/report", id), request)
+            .await
+    }
+
+    // contributor
+
+    /// Get details about a film contributor (cast or crew) by ID.
+    pub async fn contributor(&self, id: &ContributorId) -> Result<defs::Contributor> {
+        self.get(&format!("contributor/{}", id)).await
+    }
+
+    /// A cursored window over the list of contributions to films for a contributor by ID.
+    ///
+    /// Use the ‘next’ cursor to move through the list. Set the contribution type of the request
+    /// to e.g. list only the films directed by the contributor.
+    pub async fn contributor_contributions(
+        &self,
+        id: &ContributorId,
+        request: &defs::FilmContributionsRequest,
+    ) -> Result<defs::FilmContributionsResponse> {
+        self.get_with_query(&format!("contributor/{}/contributions", id), request)
+            .await
+    }
+
+    /// Stream over all contributions of the `contributor_contributions` endpoint.
+    pub fn contributor_contributions_stream(
+        &self,
+        id: &ContributorId,
+        request: defs::FilmContributionsRequest,
+    ) -> Paginator<'_, defs::FilmContributionsRequest, defs::FilmContributionsResponse> {
+        Paginator::new(self, format!("contributor/{}/contributions", id), request)
+    }
+
     // film
 
     /// A cursored window over the list of films.
@@ -165,6 +299,29 @@ impl Client {
         self.get_with_query("films", request).await
     }
 
+    /// Stream over all films of the `films` endpoint.
+    ///
+    /// The pages are requested lazily by following the ‘next’ cursor.
+    pub fn films_stream(
+        &self,
+        request: defs::FilmsRequest,
+    ) -> Paginator<'_, defs::FilmsRequest, defs::FilmsResponse> {
+        Paginator::new(self, "films".into(), request)
+    }
+
+    /// Get a list of films whose titles match the input, for type-ahead in user interfaces.
+    ///
+    /// The input is a word, partial word or phrase. At most `per_page` films are returned
+    /// (default is 20, maximum is 100).
+    pub async fn films_autocomplete(
+        &self,
+        input: &str,
+        per_page: Option<usize>,
+    ) -> Result<defs::FilmsAutocompleteResponse> {
+        let request = defs::FilmAutocompleteRequest { per_page, input };
+        self.get_with_query("films/autocomplete", &request).await
+    }
+
     /// Get a list of services supported by the /films endpoint.
     ///
     /// Services are returned in alphabetical order. Some services are only available to paying
@@ -188,24 +345,24 @@ impl Client {
     }
 
     /// Get details about a film by ID.
-    pub async fn film(&self, id: &str) -> Result<defs::Film> {
+    pub async fn film(&self, id: &FilmId) -> Result<defs::Film> {
         self.get(&format!("film/{}", id)).await
     }
 
     /// Get availability data about a film by ID.
-    pub async fn film_availability(&self, id: &str) -> Result<defs::FilmAvailabilityResponse> {
+    pub async fn film_availability(&self, id: &FilmId) -> Result<defs::FilmAvailabilityResponse> {
         self.get(&format!("film/{}/availability", id)).await
     }
 
     /// Get details of the authenticated member’s relationship with a film by ID.
-    pub async fn film_relationship(&self, id: &str) -> Result<defs::FilmAvailabilityResponse> {
+    pub async fn film_relationship(&self, id: &FilmId) -> Result<defs::FilmAvailabilityResponse> {
         self.get(&format!("film/{}/me", id)).await
     }
 
     /// Update the authenticated member’s relationship with a film by ID.
     pub async fn update_film_relationship(
         &self,
-        id: &str,
+        id: &FilmId,
         request: &defs::FilmRelationshipUpdateRequest,
     ) -> Result<defs::FilmRelationshipUpdateResponse> {
         self.patch(&format!("film/{}/me", id), request).await
@@ -214,17 +371,31 @@ impl Client {
     /// Get details of the authenticated member’s relationship with a film by ID.
     pub async fn film_relationship_members(
         &self,
-        id: &str,
+        id: &FilmId,
         request: &defs::MemberFilmRelationshipsRequest,
     ) -> Result<defs::MemberFilmRelationshipsResponse> {
         self.get_with_query(&format!("film/{}/members", id), request)
             .await
     }
 
-    //     /film/{id}/report
+    /// Stream over all members of the `film_relationship_members` endpoint.
+    pub fn film_relationship_members_stream(
+        &self,
+        id: &FilmId,
+        request: defs::MemberFilmRelationshipsRequest,
+    ) -> Paginator<'_, defs::MemberFilmRelationshipsRequest, defs::MemberFilmRelationshipsResponse>
+    {
+        Paginator::new(self, format!("film/{}/members", id), request)
+    }
+
+    /// Report a film by ID.
+    pub async fn report_film(&self, id: &FilmId, request: &defs::ReportFilmRequest) -> Result<()> {
+        self.post_without_response(&format!("film/{}/report", id), request)
+            .await
+    }
 
     /// Get statistical data about a film by ID.
-    pub async fn film_statistics(&self, id: &str) -> Result<defs::FilmStatistics> {
+    pub async fn film_statistics(&self, id: &FilmId) -> Result<defs::FilmStatistics> {
         self.get(&format!("film/{}/statistics", id)).await
     }
 
@@ -237,6 +408,14 @@ impl Client {
         self.get_with_query("lists", request).await
     }
 
+    /// Stream over all lists of the `lists` endpoint.
+    pub fn lists_stream(
+        &self,
+        request: defs::ListsRequest,
+    ) -> Paginator<'_, defs::ListsRequest, defs::ListsResponse> {
+        Paginator::new(self, "lists".into(), request)
+    }
+
     /// Create a list.
     pub async fn create_list(
         &self,
@@ -246,71 +425,381 @@ impl Client {
     }
 
     /// Get details of a list by ID.
-    pub async fn list(&self, id: &str) -> Result<defs::List> {
+    pub async fn list(&self, id: &ListId) -> Result<defs::List> {
         self.get(&format!("list/{}", id)).await
     }
 
     /// Update a list by ID.
     pub async fn update_list(
         &self,
-        id: &str,
+        id: &ListId,
         request: &defs::ListUpdateRequest,
     ) -> Result<defs::ListUpdateResponse> {
         self.patch(&format!("list/{}", id), request).await
     }
 
     /// Delete a list by ID.
-    pub async fn delete_list(&self, id: &str) -> Result<()> {
+    pub async fn delete_list(&self, id: &ListId) -> Result<()> {
         self.delete(&format!("list/{}", id)).await
     }
 
-    //     /list/{id}/comments
+    /// A cursored window over the comments for a list by ID.
+    ///
+    /// Use the ‘next’ cursor to move through the comments.
+    pub async fn list_comments(
+        &self,
+        id: &ListId,
+        request: &defs::CommentsRequest,
+    ) -> Result<defs::ListCommentsResponse> {
+        self.get_with_query(&format!("list/{}/comments", id), request)
+            .await
+    }
+
+    /// Stream over all comments of the `list_comments` endpoint.
+    pub fn list_comments_stream(
+        &self,
+        id: &ListId,
+        request: defs::CommentsRequest,
+    ) -> Paginator<'_, defs::CommentsRequest, defs::ListCommentsResponse> {
+        Paginator::new(self, format!("list/{}/comments", id), request)
+    }
+
+    /// Create a comment on a list by ID.
+    pub async fn create_list_comment(
+        &self,
+        id: &ListId,
+        request: &defs::CommentCreationRequest,
+    ) -> Result<defs::ListComment> {
+        self.post(&format!("list/{}/comments", id), request).await
+    }
 
     /// Get entries for a list by ID.
     pub async fn list_entries(
         &self,
-        id: &str,
+        id: &ListId,
         request: &defs::ListEntriesRequest,
     ) -> Result<defs::ListEntriesResponse> {
         self.get_with_query(&format!("list/{}/entries", id), request)
             .await
     }
 
-    //     /list/{id}/me
+    /// Stream over all entries of the `list_entries` endpoint.
+    pub fn list_entries_stream(
+        &self,
+        id: &ListId,
+        request: defs::ListEntriesRequest,
+    ) -> Paginator<'_, defs::ListEntriesRequest, defs::ListEntriesResponse> {
+        Paginator::new(self, format!("list/{}/entries", id), request)
+    }
 
-    //     /list/{id}/report
-    //     /list/{id}/statistics
+    /// Get details of the authenticated member’s relationship with a list by ID.
+    pub async fn list_relationship(&self, id: &ListId) -> Result<defs::ListRelationship> {
+        self.get(&format!("list/{}/me", id)).await
+    }
+
+    /// Update the authenticated member’s relationship with a list by ID.
+    pub async fn update_list_relationship(
+        &self,
+        id: &ListId,
+        request: &defs::ListRelationshipUpdateRequest,
+    ) -> Result<defs::ListRelationshipUpdateResponse> {
+        self.patch(&format!("list/{}/me", id), request).await
+    }
+
+    /// Report a list by ID.
+    pub async fn report_list(&self, id: &ListId, request: &defs::ReportListRequest) -> Result<()> {
+        self.post_without_response(&format!("list/{}/report", id), request)
+            .await
+    }
+
+    /// Get statistical data about a list by ID.
+    pub async fn list_statistics(&self, id: &ListId) -> Result<defs::ListStatistics> {
+        self.get(&format!("list/{}/statistics", id)).await
+    }
 
     // log-entry
 
-    //     /log-entries
-    //     /log-entry/{id}
-    //     /log-entry/{id}/comments
-    //     /log-entry/{id}/me
-    //     /log-entry/{id}/report
-    //     /log-entry/{id}/statistics
+    /// A cursored window over the log entries for a film or member.
+    ///
+    /// A log entry is either a diary entry (must have a date) or a review (must have review text).
+    /// Log entries can be both a diary entry and a review if they satisfy both criteria. Use the
+    /// ‘next’ cursor to move through the list.
+    pub async fn log_entries(
+        &self,
+        request: &defs::LogEntriesRequest,
+    ) -> Result<defs::LogEntriesResponse> {
+        self.get_with_query("log-entries", request).await
+    }
+
+    /// Stream over all log entries of the `log_entries` endpoint.
+    pub fn log_entries_stream(
+        &self,
+        request: defs::LogEntriesRequest,
+    ) -> Paginator<'_, defs::LogEntriesRequest, defs::LogEntriesResponse> {
+        Paginator::new(self, "log-entries".into(), request)
+    }
+
+    /// Create a log entry.
+    ///
+    /// A log entry is either a diary entry (must have a date) or a review (must have review text).
+    pub async fn create_log_entry(
+        &self,
+        request: &defs::LogEntryCreationRequest,
+    ) -> Result<defs::LogEntry> {
+        self.post("log-entries", request).await
+    }
+
+    /// Get details about a log entry by ID.
+    pub async fn log_entry(&self, id: &LogEntryId) -> Result<defs::LogEntry> {
+        self.get(&format!("log-entry/{}", id)).await
+    }
+
+    /// Update a log entry by ID.
+    pub async fn update_log_entry(
+        &self,
+        id: &LogEntryId,
+        request: &defs::LogEntryUpdateRequest,
+    ) -> Result<defs::ReviewUpdateResponse> {
+        self.patch(&format!("log-entry/{}", id), request).await
+    }
+
+    /// Delete a log entry by ID.
+    pub async fn delete_log_entry(&self, id: &LogEntryId) -> Result<()> {
+        self.delete(&format!("log-entry/{}", id)).await
+    }
+
+    /// A cursored window over the comments for a review by ID.
+    ///
+    /// Use the ‘next’ cursor to move through the comments.
+    pub async fn review_comments(
+        &self,
+        id: &LogEntryId,
+        request: &defs::CommentsRequest,
+    ) -> Result<defs::ReviewCommentsResponse> {
+        self.get_with_query(&format!("log-entry/{}/comments", id), request)
+            .await
+    }
+
+    /// Stream over all comments of the `review_comments` endpoint.
+    pub fn review_comments_stream(
+        &self,
+        id: &LogEntryId,
+        request: defs::CommentsRequest,
+    ) -> Paginator<'_, defs::CommentsRequest, defs::ReviewCommentsResponse> {
+        Paginator::new(self, format!("log-entry/{}/comments", id), request)
+    }
+
+    /// Create a comment on a review by ID.
+    pub async fn create_review_comment(
+        &self,
+        id: &LogEntryId,
+        request: &defs::CommentCreationRequest,
+    ) -> Result<defs::ReviewComment> {
+        self.post(&format!("log-entry/{}/comments", id), request)
+            .await
+    }
+
+    /// Get details of the authenticated member’s relationship with a review by ID.
+    pub async fn review_relationship(&self, id: &LogEntryId) -> Result<defs::ReviewRelationship> {
+        self.get(&format!("log-entry/{}/me", id)).await
+    }
+
+    /// Update the authenticated member’s relationship with a review by ID.
+    pub async fn update_review_relationship(
+        &self,
+        id: &LogEntryId,
+        request: &defs::ReviewRelationshipUpdateRequest,
+    ) -> Result<defs::ReviewRelationshipUpdateResponse> {
+        self.patch(&format!("log-entry/{}/me", id), request).await
+    }
+
+    /// Report a review by ID.
+    pub async fn report_review(
+        &self,
+        id: &LogEntryId,
+        request: &defs::ReportReviewRequest,
+    ) -> Result<()> {
+        self.post_without_response(&format!("log-entry/{}/report", id), request)
+            .await
+    }
+
+    /// Get statistical data about a log entry by ID.
+    pub async fn log_entry_statistics(&self, id: &LogEntryId) -> Result<defs::ReviewStatistics> {
+        self.get(&format!("log-entry/{}/statistics", id)).await
+    }
 
     // me
 
-    //     /me
+    /// Get details about the authenticated member.
+    pub async fn me(&self) -> Result<defs::MemberAccount> {
+        self.get("me").await
+    }
+
+    /// Update the profile settings for the authenticated member.
+    pub async fn update_me(
+        &self,
+        request: &defs::MemberSettingsUpdateRequest,
+    ) -> Result<defs::MemberSettingsUpdateResponse> {
+        self.patch("me", request).await
+    }
+
     //     /me/validation-request
 
     // member
 
-    //     /members
-    //     /members/pronouns
-    //     /members/register
-    //     /member/{id}
-    //     /member/{id}/activity
+    /// A cursored window over a list of members.
+    ///
+    /// Use the ‘next’ cursor to move through the list.
+    pub async fn members(&self, request: &defs::MembersRequest) -> Result<defs::MembersResponse> {
+        self.get_with_query("members", request).await
+    }
+
+    /// Stream over all members of the `members` endpoint.
+    pub fn members_stream(
+        &self,
+        request: defs::MembersRequest,
+    ) -> Paginator<'_, defs::MembersRequest, defs::MembersResponse> {
+        Paginator::new(self, "members".into(), request)
+    }
+
+    /// Get the list of pronouns which members can choose from.
+    ///
+    /// The list is requested only once and then cached for the lifetime of the client.
+    pub async fn pronouns(&self) -> Result<defs::PronounsResponse> {
+        self.pronouns
+            .get_or_try_init(|| self.get("members/pronouns"))
+            .await
+            .cloned()
+    }
+
+    /// Create a new account.
+    ///
+    /// Use `check_username` to check the availability of the username beforehand.
+    pub async fn register(&self, request: &defs::RegisterRequest) -> Result<defs::Member> {
+        self.post("members/register", request).await
+    }
+
+    /// Get details about a member by ID.
+    pub async fn member(&self, id: &MemberId) -> Result<defs::Member> {
+        self.get(&format!("member/{}", id)).await
+    }
+
+    /// Get a list of activity for a member by ID.
+    ///
+    /// Use the ‘next’ cursor to move through the list.
+    pub async fn member_activity(
+        &self,
+        id: &MemberId,
+        request: &defs::ActivityRequest,
+    ) -> Result<defs::ActivityResponse> {
+        self.get_with_query(&format!("member/{}/activity", id), request)
+            .await
+    }
+
+    /// Stream over all activity items of the `member_activity` endpoint.
+    pub fn member_activity_stream(
+        &self,
+        id: &MemberId,
+        request: defs::ActivityRequest,
+    ) -> Paginator<'_, defs::ActivityRequest, defs::ActivityResponse> {
+        Paginator::new(self, format!("member/{}/activity", id), request)
+    }
+
     //     /member/{id}/list-tags
-    //     /member/{id}/list-tags-2
-    //     /member/{id}/log-entry-tags
-    //     /member/{id}/me
-    //     /member/{id}/report
+
+    /// Get the tags a member has used for lists, with their usage counts.
+    ///
+    /// The tags are ordered by frequency of use.
+    pub async fn member_list_tags(
+        &self,
+        id: &MemberId,
+        request: &defs::MemberTagsRequest,
+    ) -> Result<defs::MemberTagsResponse> {
+        self.get_with_query(&format!("member/{}/list-tags-2", id), request)
+            .await
+    }
+
+    /// Get the tags a member has used for log entries, with their usage counts.
+    ///
+    /// The tags are ordered by frequency of use.
+    pub async fn member_log_entry_tags(
+        &self,
+        id: &MemberId,
+        request: &defs::MemberTagsRequest,
+    ) -> Result<defs::MemberTagsResponse> {
+        self.get_with_query(&format!("member/{}/log-entry-tags", id), request)
+            .await
+    }
+
+    /// Get details of the authenticated member’s relationship with another member by ID.
+    pub async fn member_relationship(&self, id: &MemberId) -> Result<defs::MemberRelationship> {
+        self.get(&format!("member/{}/me", id)).await
+    }
+
+    /// Update the authenticated member’s relationship with another member by ID, i.e. follow,
+    /// unfollow, block or unblock them.
+    ///
+    /// A rejected update is reported in the messages of the response, cf.
+    /// `MemberRelationshipUpdateResponse::into_result`.
+    pub async fn update_member_relationship(
+        &self,
+        id: &MemberId,
+        request: &defs::MemberRelationshipUpdateRequest,
+    ) -> Result<defs::MemberRelationshipUpdateResponse> {
+        self.patch(&format!("member/{}/me", id), request).await
+    }
+
+    /// Report a member by ID.
+    pub async fn report_member(
+        &self,
+        id: &MemberId,
+        request: &defs::ReportMemberRequest,
+    ) -> Result<()> {
+        self.post_without_response(&format!("member/{}/report", id), request)
+            .await
+    }
+
     //     /member/{id}/review-tags
-    //     /member/{id}/review-tags-2
-    //     /member/{id}/statistics
-    //     /member/{id}/watchlist
+
+    /// Get the tags a member has used for reviews, with their usage counts.
+    ///
+    /// The tags are ordered by frequency of use.
+    pub async fn member_review_tags(
+        &self,
+        id: &MemberId,
+        request: &defs::MemberTagsRequest,
+    ) -> Result<defs::MemberTagsResponse> {
+        self.get_with_query(&format!("member/{}/review-tags-2", id), request)
+            .await
+    }
+
+    /// Get statistical data about a member by ID.
+    pub async fn member_statistics(&self, id: &MemberId) -> Result<defs::MemberStatistics> {
+        self.get(&format!("member/{}/statistics", id)).await
+    }
+
+    /// Get details of a member’s public watchlist by ID.
+    ///
+    /// The response will include the film relationships for the signed-in member, the watchlist’s
+    /// owner, and the member indicated by the member LID if specified. Use the ‘next’ cursor to
+    /// move through the list.
+    pub async fn member_watchlist(
+        &self,
+        id: &MemberId,
+        request: &defs::WatchlistRequest,
+    ) -> Result<defs::FilmsResponse> {
+        self.get_with_query(&format!("member/{}/watchlist", id), request)
+            .await
+    }
+
+    /// Stream over all films of the `member_watchlist` endpoint.
+    pub fn member_watchlist_stream(
+        &self,
+        id: &MemberId,
+        request: defs::WatchlistRequest,
+    ) -> Paginator<'_, defs::WatchlistRequest, defs::FilmsResponse> {
+        Paginator::new(self, format!("member/{}/watchlist", id), request)
+    }
 
     // search
 
@@ -319,6 +808,14 @@ impl Client {
         self.get_with_query("search", request).await
     }
 
+    /// Stream over all search results of the `search` endpoint.
+    pub fn search_stream(
+        &self,
+        request: defs::SearchRequest,
+    ) -> Paginator<'_, defs::SearchRequest, defs::SearchResponse> {
+        Paginator::new(self, "search".into(), request)
+    }
+
     // helper methods
 
     // request helper
@@ -331,7 +828,7 @@ impl Client {
             .await
     }
 
-    async fn get_with_query<Q, R>(&self, endpoint_path: &str, query: &Q) -> Result<R>
+    pub(crate) async fn get_with_query<Q, R>(&self, endpoint_path: &str, query: &Q) -> Result<R>
     where
         Q: Serialize,
         R: DeserializeOwned + 'static,
@@ -358,6 +855,24 @@ impl Client {
             .await
     }
 
+    /// Posts the body and ignores the content of the response.
+    async fn post_without_response<B>(&self, endpoint_path: &str, body: &B) -> Result<()>
+    where
+        B: Serialize,
+    {
+        let content_type = HeaderValue::from_static("application/json");
+        let body = serde_json::to_vec(body)?;
+        self.request_bytes::<()>(
+            Method::POST,
+            endpoint_path,
+            None,
+            Some(content_type),
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn delete(&self, endpoint_path: &str) -> Result<()> {
         self.request_bytes::<()>(Method::DELETE, endpoint_path, None, None, None)
             .await?;
@@ -392,38 +907,115 @@ impl Client {
         query: Option<&Q>,
         content_type: Option<HeaderValue>,
         body: Option<Vec<u8>>,
-    ) -> Result<impl Buf>
+    ) -> Result<Bytes>
     where
         Q: Serialize,
     {
-        let mut url = Url::parse(Self::API_BASE_URL)
-            .unwrap()
-            .join(endpoint_path)
-            .unwrap(); // TODO
+        let mut url = self.base_url.join(endpoint_path)?;
         let query = query.map(serde_url_params::to_string).transpose()?;
         url.set_query(query.as_ref().map(|s| s.as_ref()));
 
         let body = body.unwrap_or_default();
 
+        let token = match self.token() {
+            Some(token) if self.auto_refresh && token.is_expired() => {
+                self.refresh_expired_token(&token).await?;
+                self.token()
+            }
+            token => token,
+        };
+
+        let res = self
+            .send_with_retry(
+                method.clone(),
+                url.clone(),
+                content_type.clone(),
+                body.clone(),
+                token.as_ref(),
+            )
+            .await;
+        match (res, token) {
+            (Err(e), Some(token)) if self.auto_refresh && e.is_unauthorized() => {
+                self.refresh_expired_token(&token).await?;
+                let token = self.token();
+                self.send_with_retry(method, url, content_type, body, token.as_ref())
+                    .await
+            }
+            (res, _) => res,
+        }
+    }
+
+    /// Sends the request and repeats it according to the retry policy, if any.
+    ///
+    /// Each attempt is signed anew, since the signature includes a nonce and a timestamp.
+    async fn send_with_retry(
+        &self,
+        method: Method,
+        url: Url,
+        content_type: Option<HeaderValue>,
+        body: Vec<u8>,
+        token: Option<&defs::AccessToken>,
+    ) -> Result<Bytes> {
+        let mut attempt = 1;
+        loop {
+            let res = self
+                .send(
+                    method.clone(),
+                    url.clone(),
+                    content_type.clone(),
+                    body.clone(),
+                    token,
+                )
+                .await;
+            match (res, self.retry_policy.as_ref()) {
+                (Err(e), Some(policy)) if policy.should_retry(&method, &e, attempt) => {
+                    // on `429 Too Many Requests`, the rate limiter already holds back all
+                    // requests for the `Retry-After` duration
+                    let held_back = self.rate_limiter.is_some()
+                        && matches!(e.kind(), Kind::RateLimited(Some(_), _));
+                    if !held_back {
+                        tokio::time::sleep(policy.backoff(&e, attempt)).await;
+                    }
+                    attempt += 1;
+                }
+                (res, _) => return res,
+            }
+        }
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        content_type: Option<HeaderValue>,
+        body: Vec<u8>,
+        token: Option<&defs::AccessToken>,
+    ) -> Result<Bytes> {
+        let _permit = match self.rate_limiter {
+            Some(ref rate_limiter) => rate_limiter.acquire().await,
+            None => None,
+        };
+
         let signed_url = self.sign_url(url, &method, &body);
+        let uri: hyper::Uri = signed_url.as_str().parse()?;
+
+        let mut req = Request::builder().method(method).uri(uri.clone());
 
-        let mut req = Request::builder()
-            .method(method)
-            .uri(signed_url.as_str())
-            .header(
+        if let Some(headers) = req.headers_mut() {
+            // default headers first, so that they never override the headers set below
+            headers.extend(self.default_headers.clone());
+            headers.insert(
                 header::ACCEPT_ENCODING,
                 HeaderValue::from_static("application/json"),
-            )
-            .header(
+            );
+            headers.insert(
                 header::CONTENT_LENGTH,
                 HeaderValue::from_str(&format!("{}", body.len())).expect("invalid header value"),
             );
-
-        if let Some(headers) = req.headers_mut() {
             if let Some(content_type) = content_type {
                 headers.insert(header::CONTENT_TYPE, content_type);
             }
-            if let Some(token) = self.token.as_ref() {
+            if let Some(token) = token {
                 headers.insert(
                     header::AUTHORIZATION,
                     HeaderValue::from_str(&format!("Bearer {}", token.access_token))
@@ -433,10 +1025,34 @@ impl Client {
         }
 
         let req = req.body(Full::from(body)).expect("invalid body");
-        let resp = self.http_client.request(req).await?;
-        let status = resp.status();
-
-        let mut buf = resp.into_body().collect().await?.to_bytes();
+        let resp = async {
+            let resp = self.http_client.send_request(req).await?;
+            let (parts, body) = resp.into_parts();
+            let buf = body.collect().await?.to_bytes();
+            Ok::<_, Error>((parts, buf))
+        };
+        let (parts, mut buf) = match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, resp)
+                .await
+                .map_err(|_| Error::timeout(uri.clone()))??,
+            None => resp.await?,
+        };
+        let status = parts.status;
+        // only the delay-seconds form of `Retry-After` is supported, not HTTP dates
+        let retry_after = parts
+            .headers
+            .get(header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+            .map(Duration::from_secs);
+
+        if let Some(ref rate_limiter) = self.rate_limiter {
+            if status == hyper::StatusCode::TOO_MANY_REQUESTS {
+                rate_limiter.rate_limited(retry_after);
+            } else if status.is_success() {
+                rate_limiter.succeeded();
+            }
+        }
 
         if !status.is_success() {
             let mut content = String::new();
@@ -444,16 +1060,84 @@ impl Client {
                 content.push_str(&String::from_utf8_lossy(buf.chunk()));
                 buf.advance(buf.chunk().len());
             }
-            return Err(Error::server_error(
-                status,
-                content,
-                signed_url.as_str().parse()?,
-            ));
+            return Err(Error::server_error(status, retry_after, content, uri));
         }
 
         Ok(buf)
     }
 
+    // token helper
+
+    /// Requests a new token from the `auth/token` endpoint.
+    ///
+    /// The request is sent form url encoded and without the current token.
+    async fn request_token<B: Serialize>(&self, request: &B) -> Result<defs::AccessToken> {
+        let content_type = HeaderValue::from_static("application/x-www-form-urlencoded");
+        let body = serde_url_params::to_vec(request)?;
+        let url = self.base_url.join("auth/token")?;
+        let buf = self
+            .send(Method::POST, url, Some(content_type), body, None)
+            .await
+            .map_err(Error::into_oauth_error)?;
+        Ok(serde_json::from_reader(&mut buf.reader())?)
+    }
+
+    /// Refreshes the token, unless it was already replaced since `expired` was observed.
+    ///
+    /// If the token store holds a newer token, e.g. refreshed by another process sharing the
+    /// store, the stored token is used instead, since the refresh token of `expired` might be
+    /// used up already.
+    async fn refresh_expired_token(&self, expired: &defs::AccessToken) -> Result<()> {
+        let _guard = self.refresh_lock.lock().await;
+        let current = self.token();
+        if current.map(|token| token.access_token) != Some(expired.access_token.clone()) {
+            // another request refreshed the token while we were waiting for the lock
+            return Ok(());
+        }
+        if let Some(token_store) = self.token_store.as_ref() {
+            if let Some(stored) = token_store.load()? {
+                if stored.access_token != expired.access_token {
+                    let is_expired = stored.is_expired();
+                    self.replace_token(Some(stored));
+                    if !is_expired {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        self.request_refreshed_token().await
+    }
+
+    /// Must be called with the `refresh_lock` being held.
+    async fn request_refreshed_token(&self) -> Result<()> {
+        #[derive(Debug, Serialize)]
+        struct RefreshRequest<'a> {
+            grant_type: &'static str,
+            refresh_token: &'a str,
+        }
+
+        let token = self.token().ok_or_else(Error::not_authenticated)?;
+        let request = RefreshRequest {
+            grant_type: "refresh_token",
+            refresh_token: &token.refresh_token,
+        };
+        let token = self.request_token(&request).await?;
+        self.set_token(Some(token))
+    }
+
+    fn attach_token_store(&mut self, token_store: Arc<dyn TokenStore>) -> Result<()> {
+        match self.token() {
+            Some(token) => token_store.save(&token)?,
+            None => self.replace_token(token_store.load()?),
+        }
+        self.token_store = Some(token_store);
+        Ok(())
+    }
+
+    fn replace_token(&self, token: Option<defs::AccessToken>) {
+        *self.token.write().expect("poisoned token lock") = token;
+    }
+
     /// Signs the request based on a random and unique nonce, timestamp, and
     /// client id and secret.
     ///
@@ -484,9 +1168,9 @@ impl Client {
         let mut hmac = HmacSha256::new_from_slice(self.api_key_pair.api_secret.as_bytes())
             .expect("HMAC can take key of any size");
         hmac.update(method.as_str().as_bytes());
-        hmac.update(&[b'\0']);
+        hmac.update(b"\0");
         hmac.update(url.as_str().as_bytes());
-        hmac.update(&[b'\0']);
+        hmac.update(b"\0");
         hmac.update(body);
         let signature: String = hmac.finalize().into_bytes().encode_hex();
 
@@ -500,13 +1184,307 @@ impl fmt::Debug for Client {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Client")
             .field("api_key_pair", &"[hidden]")
+            .field("base_url", &self.base_url.as_str())
             .field("token", &self.token)
-            .field("http_client", &self.http_client)
+            .field("auto_refresh", &self.auto_refresh)
+            .field("token_store", &self.token_store)
+            .field("default_headers", &self.default_headers)
+            .field("timeout", &self.timeout)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limiter", &self.rate_limiter)
             .finish()
     }
 }
 
-fn http_client() -> HttpClient<HttpsConnector<HttpConnector>, Full<Bytes>> {
-    let https = HttpsConnector::new();
-    HttpClient::builder(hyper_util::rt::TokioExecutor::new()).build(https)
+/// Builder of a [Client](struct.Client.html) with custom configuration.
+///
+/// ```rust,no_run
+/// # fn build() -> letterboxd::Result<()> {
+/// let api_key_pair = letterboxd::ApiKeyPair::from_env().unwrap();
+/// let client = letterboxd::Client::builder(api_key_pair)
+///     .base_url("http://localhost:8080/api/v0/")
+///     .timeout(std::time::Duration::from_secs(10))
+///     .user_agent("my-app/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientBuilder {
+    api_key_pair: ApiKeyPair,
+    base_url: String,
+    token: Option<defs::AccessToken>,
+    token_store: Option<Arc<dyn TokenStore>>,
+    auto_refresh: bool,
+    user_agent: Option<String>,
+    default_headers: HeaderMap,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limit: Option<RateLimit>,
+    http_client: Option<Arc<dyn SendRequest>>,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder with the default configuration.
+    pub fn new(api_key_pair: ApiKeyPair) -> Self {
+        Self {
+            api_key_pair,
+            base_url: Client::API_BASE_URL.into(),
+            token: None,
+            token_store: None,
+            auto_refresh: false,
+            user_agent: None,
+            default_headers: HeaderMap::new(),
+            timeout: None,
+            connect_timeout: None,
+            retry_policy: None,
+            rate_limit: None,
+            http_client: None,
+        }
+    }
+
+    /// Sets the base url against which the endpoint paths are resolved.
+    ///
+    /// Defaults to `Client::API_BASE_URL`. Plain `http` urls are supported, e.g. to talk to a
+    /// local stand-in server.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the token used for authentication.
+    ///
+    /// It is not checked that the token is valid.
+    pub fn token(mut self, token: defs::AccessToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Attaches a token store to the client, cf. `Client::set_token_store`.
+    pub fn token_store(mut self, token_store: impl TokenStore + 'static) -> Self {
+        self.token_store = Some(Arc::new(token_store));
+        self
+    }
+
+    /// Enables or disables the automatic renewal of the access token, cf.
+    /// `Client::set_auto_refresh`.
+    pub fn auto_refresh(mut self, auto_refresh: bool) -> Self {
+        self.auto_refresh = auto_refresh;
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with each request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Adds a header which is sent with each request.
+    ///
+    /// Headers set by the client itself, i.e. `Accept-Encoding`, `Content-Length`, `Content-Type`
+    /// and `Authorization`, take precedence over a default header with the same name.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Sets the timeout of a request, from sending the request until the response body is
+    /// received.
+    ///
+    /// There is no timeout by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    ///
+    /// Only applies to the default connector, not to a custom `connector`.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Retries requests which failed with a transient error according to the given policy.
+    ///
+    /// Requests are not retried by default.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Throttles requests according to the given rate limit.
+    ///
+    /// The limit applies to the client and all its clones together. Requests are not throttled
+    /// by default.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Uses a custom hyper connector instead of the default `HttpsConnector`.
+    ///
+    /// E.g. a plain `HttpConnector` allows to talk to a local stand-in server without TLS.
+    pub fn connector<C>(mut self, connector: C) -> Self
+    where
+        C: Connect + Clone + Send + Sync + 'static,
+    {
+        let http_client = HttpClient::builder(hyper_util::rt::TokioExecutor::new())
+            .build::<_, Full<Bytes>>(connector);
+        self.http_client = Some(Arc::new(http_client));
+        self
+    }
+
+    /// Creates the client.
+    ///
+    /// Fails if the base url or the user agent is invalid, or if the token could not be loaded
+    /// from or saved to the token store.
+    pub fn build(self) -> Result<Client> {
+        let mut base_url = self.base_url;
+        // without a trailing slash, the last path segment would be replaced when joining
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        let base_url = Url::parse(&base_url)?;
+
+        let mut default_headers = self.default_headers;
+        if let Some(user_agent) = self.user_agent {
+            default_headers.insert(header::USER_AGENT, HeaderValue::from_str(&user_agent)?);
+        }
+
+        let connect_timeout = self.connect_timeout;
+        let http_client = self.http_client.unwrap_or_else(|| {
+            let mut http = HttpConnector::new();
+            http.enforce_http(false);
+            http.set_connect_timeout(connect_timeout);
+            let https = HttpsConnector::new_with_connector(http);
+            Arc::new(
+                HttpClient::builder(hyper_util::rt::TokioExecutor::new())
+                    .build::<_, Full<Bytes>>(https),
+            )
+        });
+
+        let mut client = Client {
+            api_key_pair: self.api_key_pair,
+            base_url,
+            token: Arc::new(RwLock::new(self.token)),
+            auto_refresh: self.auto_refresh,
+            refresh_lock: Arc::new(tokio::sync::Mutex::new(())),
+            token_store: None,
+            default_headers,
+            timeout: self.timeout,
+            retry_policy: self.retry_policy,
+            rate_limiter: self
+                .rate_limit
+                .map(|limit| Arc::new(RateLimiter::new(limit))),
+            pronouns: Arc::new(tokio::sync::OnceCell::new()),
+            http_client,
+        };
+        if let Some(token_store) = self.token_store {
+            client.attach_token_store(token_store)?;
+        }
+        Ok(client)
+    }
+
+    /// Creates the client and authenticates it with the given username/password.
+    ///
+    /// The client is returned authenticated even if saving the token to the token store fails,
+    /// since the token is not lost: it can be retrieved with `Client::token` and saved again
+    /// with `Client::set_token`.
+    pub async fn authenticate(self, username: &str, password: &str) -> Result<Client> {
+        #[derive(Debug, Serialize)]
+        struct AuthRequest<'a> {
+            grant_type: &'static str,
+            username: &'a str,
+            password: &'a str,
+        }
+
+        let request = AuthRequest {
+            grant_type: "password",
+            username,
+            password,
+        };
+
+        let client = self.build()?;
+        let token = client.request_token(&request).await?;
+        // a successful login is not reported as failure, if only the token store failed
+        let _ = client.set_token(Some(token));
+        Ok(client)
+    }
+}
+
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("api_key_pair", &"[hidden]")
+            .field("base_url", &self.base_url)
+            .field("token", &self.token)
+            .field("token_store", &self.token_store)
+            .field("auto_refresh", &self.auto_refresh)
+            .field("user_agent", &self.user_agent)
+            .field("default_headers", &self.default_headers)
+            .field("timeout", &self.timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limit", &self.rate_limit)
+            .field("custom_connector", &self.http_client.is_some())
+            .finish()
+    }
+}
+
+type ResponseFuture = Pin<
+    Box<
+        dyn Future<
+                Output = std::result::Result<Response<Incoming>, hyper_util::client::legacy::Error>,
+            > + Send,
+    >,
+>;
+
+/// HTTP client with the type of its connector erased.
+trait SendRequest: Send + Sync {
+    fn send_request(&self, req: Request<Full<Bytes>>) -> ResponseFuture;
+}
+
+impl<C> SendRequest for HttpClient<C, Full<Bytes>>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn send_request(&self, req: Request<Full<Bytes>>) -> ResponseFuture {
+        Box::pin(self.request(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct FailingTokenStore;
+
+    impl TokenStore for FailingTokenStore {
+        fn load(&self) -> Result<Option<defs::AccessToken>> {
+            Ok(None)
+        }
+
+        fn save(&self, _token: &defs::AccessToken) -> Result<()> {
+            Err(std::io::Error::other("disk full").into())
+        }
+
+        fn clear(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn token_is_kept_if_store_fails() {
+        let api_key_pair = ApiKeyPair::new("key".into(), "secret".into());
+        let client = Client::with_token_store(api_key_pair, FailingTokenStore).unwrap();
+        let token = defs::AccessToken {
+            access_token: "access".into(),
+            ..Default::default()
+        };
+        let err = client.set_token(Some(token)).unwrap_err();
+        assert!(matches!(err.kind(), Kind::Io(_)));
+        assert_eq!(client.token().unwrap().access_token, "access");
+    }
 }
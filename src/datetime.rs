@@ -0,0 +1,144 @@
+//! Typed accessors for timestamp and date fields, enabled by the `chrono`
+//! feature.
+//!
+//! The fields themselves are kept as ISO 8601 strings, so that enabling the
+//! feature does not change any public type.
+
+use crate::defs::{
+    AbstractActivity, AbstractComment, DiaryDetails, List, ListComment, LogEntry, Review,
+    ReviewComment,
+};
+
+use chrono::{DateTime, NaiveDate, ParseResult, Utc};
+
+/// Parses a timestamp in ISO 8601 format with UTC timezone, i.e.
+/// YYYY-MM-DDThh:mm:ssZ: "1997-08-29T07:14:00Z".
+fn parse_timestamp(timestamp: &str) -> ParseResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp).map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+/// Parses a date in ISO 8601 format, i.e. YYYY-MM-DD: "1997-08-29".
+fn parse_date(date: &str) -> ParseResult<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+}
+
+macro_rules! timestamps {
+    ($($ty:ty { $($field:ident),* $(,)? })*) => {
+        $(
+            impl $ty {
+                $(
+                    #[doc = concat!("Parses `", stringify!($field), "` as timestamp in UTC.")]
+                    pub fn $field(&self) -> ParseResult<DateTime<Utc>> {
+                        parse_timestamp(&self.$field)
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+timestamps! {
+    ListComment { when_created, when_updated }
+    LogEntry { when_created, when_updated }
+    Review { when_reviewed }
+    ReviewComment { when_created, when_updated }
+}
+
+impl List {
+    /// Parses `when_created` as timestamp in UTC.
+    pub fn when_created(&self) -> ParseResult<DateTime<Utc>> {
+        parse_timestamp(&self.when_created)
+    }
+
+    /// Parses `when_published` as timestamp in UTC, if the list was published.
+    pub fn when_published(&self) -> Option<ParseResult<DateTime<Utc>>> {
+        self.when_published.as_deref().map(parse_timestamp)
+    }
+}
+
+impl DiaryDetails {
+    /// Parses `diary_date` as date.
+    pub fn diary_date(&self) -> ParseResult<NaiveDate> {
+        parse_date(&self.diary_date)
+    }
+}
+
+impl AbstractComment {
+    /// Parses `when_created` of the comment as timestamp in UTC.
+    pub fn when_created(&self) -> ParseResult<DateTime<Utc>> {
+        match self {
+            AbstractComment::ListComment { when_created, .. }
+            | AbstractComment::ReviewComment { when_created, .. } => parse_timestamp(when_created),
+        }
+    }
+
+    /// Parses `when_updated` of the comment as timestamp in UTC.
+    pub fn when_updated(&self) -> ParseResult<DateTime<Utc>> {
+        match self {
+            AbstractComment::ListComment { when_updated, .. }
+            | AbstractComment::ReviewComment { when_updated, .. } => parse_timestamp(when_updated),
+        }
+    }
+}
+
+impl AbstractActivity {
+    /// Parses `when_created` of the activity as timestamp in UTC.
+    ///
+    /// Returns `None` for an unknown activity.
+    pub fn when_created(&self) -> Option<ParseResult<DateTime<Utc>>> {
+        match self {
+            AbstractActivity::DiaryEntryActivity { when_created, .. }
+            | AbstractActivity::FilmLikeActivity { when_created, .. }
+            | AbstractActivity::FilmRatingActivity { when_created, .. }
+            | AbstractActivity::FilmWatchActivity { when_created, .. }
+            | AbstractActivity::FollowActivity { when_created, .. }
+            | AbstractActivity::InvitationAcceptedActivity { when_created, .. }
+            | AbstractActivity::ListActivity { when_created, .. }
+            | AbstractActivity::ListCommentActivity { when_created, .. }
+            | AbstractActivity::ListLikeActivity { when_created, .. }
+            | AbstractActivity::RegistrationActivity { when_created, .. }
+            | AbstractActivity::ReviewActivity { when_created, .. }
+            | AbstractActivity::ReviewCommentActivity { when_created, .. }
+            | AbstractActivity::ReviewLikeActivity { when_created, .. }
+            | AbstractActivity::WatchlistActivity { when_created, .. } => {
+                Some(parse_timestamp(when_created))
+            }
+            AbstractActivity::Unknown => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{Datelike, Timelike};
+
+    #[test]
+    fn timestamps_and_dates_are_parsed() {
+        let json = r#"{
+            "lbml": "Great film",
+            "containsSpoilers": false,
+            "whenReviewed": "1997-08-29T07:14:00Z",
+            "text": "<p>Great film</p>"
+        }"#;
+        let review: Review = serde_json::from_str(json).unwrap();
+        assert_eq!(review.when_reviewed, "1997-08-29T07:14:00Z");
+        let when_reviewed = review.when_reviewed().unwrap();
+        assert_eq!(when_reviewed.year(), 1997);
+        assert_eq!(when_reviewed.hour(), 7);
+
+        let json = r#"{ "diaryDate": "2019-01-01", "rewatch": true }"#;
+        let details: DiaryDetails = serde_json::from_str(json).unwrap();
+        let diary_date = details.diary_date().unwrap();
+        assert_eq!(diary_date.ordinal(), 1);
+        // request fields take the date formatted as string
+        assert_eq!(diary_date.succ_opt().unwrap().to_string(), "2019-01-02");
+
+        let details = DiaryDetails {
+            diary_date: "01/01/2019".into(),
+            ..details
+        };
+        assert!(details.diary_date().is_err());
+    }
+}
@@ -10,13 +10,21 @@
 //! Therefore, most of the types below have to be adjusted with optional
 //! values. Further, only the types that are in the API implementation are
 //! public.
+//!
+//! Fields of the update requests which are `None` are not sent, so that a
+//! PATCH request only updates the fields which are set.
+
+use crate::id::{CommentId, ContributorId, FilmId, ListId, LogEntryId, MemberId};
 
 use serde::{Deserialize, Serialize};
 
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 #[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all_fields = "camelCase")]
 #[serde(tag = "type")]
-enum AbstractActivity {
+pub enum AbstractActivity {
     /// Common fields:
     /// member The member associated with the activity.
     /// when_created The timestamp of the activity, in ISO 8601 format with UTC
@@ -115,15 +123,18 @@ enum AbstractActivity {
         /// MemberFilmRelationship for the member who added the activity.
         film: FilmSummary,
     },
+    /// An activity type which is not (yet) known to this crate.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all_fields = "camelCase")]
 #[serde(tag = "type")]
-enum AbstractComment {
+pub enum AbstractComment {
     ListComment {
         /// The LID of the comment.
-        id: String,
+        id: CommentId,
         /// The member who posted the comment.
         member: MemberSummary,
         /// ISO 8601 format with UTC timezone, i.e. YYYY-MM-DDThh:mm:ssZ:
@@ -135,7 +146,7 @@ enum AbstractComment {
         /// The message portion of the comment in LBML. May contain the
         /// following HTML tags: `<br>` `<strong>` `<em>` `<b>` `<i>` `<a
         /// href="">` `<blockquote>`.
-        comment_lbml: String,
+        comment_lbml: Option<String>,
         /// If Letterboxd moderators have removed the comment from the site,
         /// removedByAdmin will be true and comment will not be included.
         removed_by_admin: bool,
@@ -155,11 +166,11 @@ enum AbstractComment {
         /// The list on which the comment was posted.
         list: ListIdentifier,
         /// The message portion of the comment formatted as HTML.
-        comment: String,
+        comment: Option<String>,
     },
     ReviewComment {
         /// The LID of the comment.
-        id: String,
+        id: CommentId,
         /// The member who posted the comment.
         member: MemberSummary,
         /// ISO 8601 format with UTC timezone, i.e. YYYY-MM-DDThh:mm:ssZ:
@@ -171,7 +182,7 @@ enum AbstractComment {
         /// The message portion of the comment in LBML. May contain the
         /// following HTML tags: `<br>` `<strong>` `<em>` `<b>` `<i>` `<a
         /// href="">` `<blockquote>`.
-        comment_lbml: String,
+        comment_lbml: Option<String>,
         /// If Letterboxd moderators have removed the comment from the site,
         /// removedByAdmin will be true and comment will not be included.
         removed_by_admin: bool,
@@ -191,7 +202,7 @@ enum AbstractComment {
         /// The review on which the comment was posted.
         review: ReviewIdentifier,
         /// The message portion of the comment formatted as HTML.
-        comment: String,
+        comment: Option<String>,
     },
 }
 
@@ -229,7 +240,7 @@ pub enum AbstractSearchItem {
     },
 }
 
-#[derive(Deserialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct AccessToken {
     /// The access token that grants the member access. Combine this with the
     /// token_type to form the Authorization header.
@@ -244,10 +255,33 @@ pub struct AccessToken {
     pub refresh_token: String,
     /// The number of seconds before the access token expires.
     pub expires_in: usize,
+    /// Not part of the API: the time the token was issued at, in seconds since
+    /// the Unix epoch. Recorded when the token is received from the API.
+    #[serde(default = "unix_timestamp_now")]
+    pub issued_at: u64,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-enum ActivityClass {
+impl AccessToken {
+    /// The time at which the access token expires.
+    pub fn expires_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.issued_at + self.expires_in as u64)
+    }
+
+    /// Returns true if the access token is expired.
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() >= self.expires_at()
+    }
+}
+
+fn unix_timestamp_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("SystemTime::duration_since failed")
+        .as_secs()
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub enum ActivityClass {
     OwnActivity,
     NotOwnActivity,
     IncomingActivity,
@@ -255,13 +289,13 @@ enum ActivityClass {
     NetworkActivity,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-struct ActivityRequest {
+pub struct ActivityRequest {
     /// The pagination cursor.
-    cursor: Option<Cursor>,
+    pub cursor: Option<Cursor>,
     /// The number of items to include per page (default is 20, maximum is 100).
-    per_page: Option<usize>,
+    pub per_page: Option<usize>,
     /// Only supported for paying members.
     /// Use include to specify the subset of activity to be returned. If
     /// neither include nor exclude is set, the activity types included depend
@@ -273,7 +307,7 @@ struct ActivityRequest {
     /// FilmRatingActivity, FollowActivity, RegistrationActivity and
     /// InvitationAcceptedActivity is included.
     /// These defaults mimic those shown on the website.
-    include: Option<Vec<ActivityType>>,
+    pub include: Option<Vec<ActivityType>>,
     /// Use where to reduce the subset of activity to be returned. If where is
     /// not set, all default activity types relating to the member are
     /// returned. If multiple values are supplied, only activity matching all
@@ -287,11 +321,11 @@ struct ActivityRequest {
     /// to the member’s content from members outside their network (e.g.
     /// comments and likes on the member’s lists and reviews).
     #[serde(rename = "where")]
-    where_activity: Option<Vec<ActivityClass>>,
+    pub where_activity: Option<Vec<ActivityClass>>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-enum ActivityType {
+#[derive(Serialize, Debug, Clone)]
+pub enum ActivityType {
     ReviewActivity,
     ReviewCommentActivity,
     ReviewLikeActivity,
@@ -309,11 +343,11 @@ enum ActivityType {
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ActivityResponse {
+pub struct ActivityResponse {
     /// The cursor to the next page of results.
-    next: Option<Cursor>,
+    pub next: Option<Cursor>,
     /// The list of activity items.
-    items: Vec<AbstractActivity>,
+    pub items: Vec<AbstractActivity>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -321,11 +355,17 @@ pub struct CommentCreationRequest {
     /// The message portion of the comment in LBML. May contain the following
     /// HTML tags: `<br>` `<strong>` `<em>` `<b>` `<i>` `<a href="">`
     /// `<blockquote>`. This field has a maximum size of 100,000 characters.
-    comment: String,
+    pub comment: String,
+}
+
+impl CommentCreationRequest {
+    pub fn new(comment: String) -> Self {
+        Self { comment }
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
-enum CommentUpdateMessageCode {
+pub enum CommentUpdateMessageCode {
     MissingComment,
     CommentOnContentYouBlocked,
     CommentOnBlockedContent,
@@ -336,7 +376,7 @@ enum CommentUpdateMessageCode {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
-enum CommentUpdateMessage {
+pub enum CommentUpdateMessage {
     Error {
         /// The error message code.
         code: CommentUpdateMessageCode,
@@ -346,53 +386,59 @@ enum CommentUpdateMessage {
     Success,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-struct CommentUpdateRequest {
+#[derive(Serialize, Debug, Clone)]
+pub struct CommentUpdateRequest {
     /// The message portion of the comment in LBML. May contain the following
     /// HTML tags: `<br>` `<strong>` `<em>` `<b>` `<i>` `<a href="">`
     /// `<blockquote>`. This field has a maximum size of 100,000 characters.
-    comment: String,
+    pub comment: String,
+}
+
+impl CommentUpdateRequest {
+    pub fn new(comment: String) -> Self {
+        Self { comment }
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct CommentUpdateResponse {
+pub struct CommentUpdateResponse {
     /// The response object.
-    data: AbstractComment,
+    pub data: AbstractComment,
     /// A list of messages the API client should show to the user.
-    messages: Vec<CommentUpdateMessage>,
+    pub messages: Vec<CommentUpdateMessage>,
 }
 
 // TODO: Ordering
-#[derive(Deserialize, Debug, Clone)]
-enum CommentsRequestSort {
+#[derive(Serialize, Debug, Clone)]
+pub enum CommentsRequestSort {
     Date,
     Updates,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-struct CommentsRequest {
+pub struct CommentsRequest {
     /// The pagination cursor.
-    cursor: Option<Cursor>,
+    pub cursor: Option<Cursor>,
     /// The number of items to include per page (default is 20, maximum is 100).
-    per_page: Option<usize>,
+    pub per_page: Option<usize>,
     /// Defaults to Date. The Updates sort order returns newest content first.
     /// Use this to get the most recently posted or edited comments, and pass
     /// include_deletions=true to remain consistent in the case where a comment
     /// has been deleted.
-    sort: CommentsRequestSort,
+    pub sort: Option<CommentsRequestSort>,
     /// Use this to discover any comments that were deleted.
-    include_deletions: bool,
+    pub include_deletions: Option<bool>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct ContributionStatistics {
+pub struct ContributionStatistics {
     /// The type of contribution.
     #[serde(rename = "type")]
-    contribution_type: ContributionType,
+    pub contribution_type: ContributionType,
     /// The number of films for this contribution type.
-    film_count: usize,
+    pub film_count: usize,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -415,28 +461,28 @@ pub enum ContributionType {
 #[derive(Deserialize, Debug, Clone)]
 pub struct Contributor {
     /// The LID of the contributor.
-    pub id: String,
+    pub id: ContributorId,
     /// The name of the contributor.
     pub name: String,
     /// An array of the types of contributions made, with a count of films for
-    /// each contribution type.
-    // TODO
-    // statistics: ContributorStatistics,
-    // A list of relevant URLs to this entity, on Letterboxd and external sites.
+    /// each contribution type. Only included in the details of a contributor,
+    /// but not e.g. in search results.
+    pub statistics: Option<ContributorStatistics>,
+    /// A list of relevant URLs to this entity, on Letterboxd and external sites.
     pub links: Vec<Link>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ContributorStatistics {
-    // The statistics for each contribution type.
-    contributions: Vec<ContributionStatistics>,
+pub struct ContributorStatistics {
+    /// The statistics for each contribution type.
+    pub contributions: Vec<ContributionStatistics>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ContributorSummary {
     /// The LID of the contributor.
-    pub id: String,
+    pub id: ContributorId,
     /// The name of the contributor.
     pub name: String,
     /// The character name if available (only if the contribution is as an
@@ -463,7 +509,7 @@ pub struct DiaryDetails {
 #[serde(rename_all = "camelCase")]
 pub struct Film {
     /// The LID of the film.
-    pub id: String,
+    pub id: FilmId,
     /// The title of the film.
     pub name: String,
     /// The original title of the film, if it was first released with a
@@ -500,13 +546,13 @@ pub struct Film {
     pub links: Vec<Link>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct FilmAutocompleteRequest {
+pub(crate) struct FilmAutocompleteRequest<'a> {
     /// The number of items to include per page (default is 20, maximum is 100).
-    per_page: Option<usize>,
+    pub per_page: Option<usize>,
     /// The word, partial word or phrase to match against.
-    input: String,
+    pub input: &'a str,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -671,14 +717,14 @@ pub struct FilmAvailabilityResponse {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct FilmContribution {
+pub struct FilmContribution {
     /// The type of contribution.
     #[serde(rename = "type")]
-    contribution_type: ContributionType,
+    pub contribution_type: ContributionType,
     /// The film.
-    film: FilmSummary,
+    pub film: FilmSummary,
     /// The name of the character (only when type is Actor).
-    character_name: String,
+    pub character_name: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -716,7 +762,7 @@ pub enum FilmRelationshipType {
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum FilmContributionsSort {
+pub enum FilmContributionsSort {
     FilmName,
     ReleaseDateLatestFirst,
     ReleaseDateEarliestFirst,
@@ -730,74 +776,74 @@ enum FilmContributionsSort {
     FilmPopularityThisYear,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-struct FilmContributionsRequest {
+pub struct FilmContributionsRequest {
     /// The pagination cursor.
-    cursor: Option<Cursor>,
+    pub cursor: Option<Cursor>,
     /// The number of items to include per page (default is 20, maximum is 100).
-    per_page: Option<usize>,
+    pub per_page: Option<usize>,
     /// The order in which the films should be returned. Defaults to
     /// FilmPopularity, which is an all-time measurement of the amount of
     /// activity the film has received. The FilmPopularityWithFriends values
     /// are only available to signed-in members and consider popularity amongst
     /// the signed-in member’s friends.
-    sort: FilmContributionsSort,
+    pub sort: Option<FilmContributionsSort>,
     /// The type of contribution.
     #[serde(rename = "type")]
-    contribution_type: ContributionType,
+    pub contribution_type: Option<ContributionType>,
     /// Specify the LID of a genre to limit films to those within the specified
     /// genre.
-    genre: String,
+    pub genre: Option<String>,
     /// Specify the starting year of a decade (must end in 0) to limit films to
     /// those released during the decade. 1990
-    decade: u16,
+    pub decade: Option<u16>,
     /// Specify a year to limit films to those released during that year. 1994
-    year: u16,
+    pub year: Option<u16>,
     /// Specify the ID of a supported service to limit films to those available
     /// from that service. The list of available services can be found by using
     /// the /films/film-services endpoint.
-    service: String,
+    pub service: Option<String>,
     /// Specify one or more values to limit the list of films accordingly.
     /// where=Watched&where=Released
     #[serde(rename = "where")]
-    where_film_status: Vec<FilmStatus>,
+    pub where_film_status: Vec<FilmStatus>,
     /// Specify the LID of a member to limit the returned films according to
     /// the value set in memberRelationship.
-    member: String,
+    pub member: Option<MemberId>,
     /// Must be used in conjunction with member. Defaults to Watched. Specify
     /// the type of relationship to limit the returned films accordingly.
-    member_relationship: FilmRelationshipType,
+    pub member_relationship: Option<FilmRelationshipType>,
     /// Must be used in conjunction with member. Defaults to None, which only
     /// returns films from the member’s account. Use Only to return films from
     /// the member’s friends, and All to return films from both the member and
     /// their friends.
-    include_friends: IncludeFriends,
+    pub include_friends: Option<IncludeFriends>,
     /// Specify a tag code to limit the returned films to those tagged
     /// accordingly.
-    tag_code: String,
+    pub tag_code: Option<String>,
     /// Must be used with tag. Specify the LID of a member to focus the tag
     /// filter on the member.
-    tagger: String,
+    pub tagger: Option<MemberId>,
     /// Must be used in conjunction with tagger. Defaults to None, which
     /// filters tags set by the member. Use Only to filter tags set by the
     /// member’s friends, and All to filter tags set by both the member and
     /// their friends.
-    include_tagger_friends: IncludeFriends,
+    pub include_tagger_friends: Option<IncludeFriends>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct FilmContributionsResponse {
+pub struct FilmContributionsResponse {
     /// The cursor to the next page of results.
-    next: Option<Cursor>,
+    pub next: Option<Cursor>,
     /// The list of contributions.
-    items: Vec<FilmContribution>,
+    pub items: Vec<FilmContribution>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct FilmIdentifier {
     /// The LID of the film.
-    pub id: String,
+    pub id: FilmId,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -817,10 +863,10 @@ pub struct FilmRelationship {
     pub rating: Option<f32>,
     /// A list of LIDs for reviews the member has written for the film in the
     /// order they were added, with most recent reviews first.
-    pub reviews: Vec<String>,
+    pub reviews: Vec<LogEntryId>,
     /// A list of LIDs for log entries the member has added for the film in
     /// diary order, with most recent entries first.
-    pub diary_entries: Vec<String>,
+    pub diary_entries: Vec<LogEntryId>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -915,7 +961,7 @@ pub struct FilmStatisticsCounts {
 #[serde(rename_all = "camelCase")]
 pub struct FilmSummary {
     /// The LID of the film.
-    pub id: String,
+    pub id: FilmId,
     /// The title of the film.
     pub name: String,
     /// The original title of the film, if it was first released with a
@@ -945,9 +991,9 @@ pub struct FilmTrailer {
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct FilmsAutocompleteResponse {
-    // The list of films.
-    items: Vec<FilmSummary>,
+pub struct FilmsAutocompleteResponse {
+    /// The list of films.
+    pub items: Vec<FilmSummary>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -1012,7 +1058,7 @@ pub struct FilmsRequest {
     pub where_film_status: Vec<FilmStatus>,
     /// Specify the LID of a member to limit the returned films according to
     /// the value set in memberRelationship.
-    pub member: Option<String>,
+    pub member: Option<MemberId>,
     /// Must be used in conjunction with member. Defaults to Watched. Specify
     /// the type of relationship to limit the returned films accordingly.
     pub member_relationship: Option<FilmRelationshipType>,
@@ -1026,7 +1072,7 @@ pub struct FilmsRequest {
     pub tag_code: Option<String>,
     /// Must be used with tag. Specify the LID of a member to focus the tag
     /// filter on the member.
-    pub tagger: Option<String>,
+    pub tagger: Option<MemberId>,
     /// Must be used in conjunction with tagger. Defaults to None, which
     /// filters tags set by the member. Use Only to filter tags set by the
     /// member’s friends, and All to filter tags set by both the member and
@@ -1042,10 +1088,11 @@ pub struct FilmsResponse {
     pub items: Vec<FilmSummary>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct ForgottenPasswordRequest {
-    email_address: String,
+pub(crate) struct ForgottenPasswordRequest<'a> {
+    /// The email address of the account.
+    pub email_address: &'a str,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -1125,7 +1172,7 @@ pub enum Link {
 #[serde(rename_all = "camelCase")]
 pub struct List {
     /// The LID of the list.
-    pub id: String,
+    pub id: ListId,
     /// The name of the list.
     pub name: String,
     /// The number of films in the list.
@@ -1170,56 +1217,56 @@ pub struct List {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct ListComment {
+pub struct ListComment {
     /// The LID of the comment.
-    id: String,
+    pub id: CommentId,
     /// The member who posted the comment.
-    member: MemberSummary,
+    pub member: MemberSummary,
     /// ISO 8601 format with UTC timezone, i.e. YYYY-MM-DDThh:mm:ssZ
     /// "1997-08-29T07:14:00Z"
-    when_created: String,
+    pub when_created: String,
     /// ISO 8601 format with UTC timezone, i.e. YYYY-MM-DDThh:mm:ssZ
     /// "1997-08-29T07:14:00Z"
-    when_updated: String,
+    pub when_updated: String,
     /// The message portion of the comment in LBML. May contain the following
     /// HTML tags: `<br>` `<strong>` `<em>` `<b>` `<i>` `<a href="">`
     /// `<blockquote>`.
-    comment_lbml: String,
+    pub comment_lbml: Option<String>,
     /// If Letterboxd moderators have removed the comment from the site,
     /// removedByAdmin will be true and comment will not be included.
-    removed_by_admin: bool,
+    pub removed_by_admin: bool,
     /// If the comment owner has removed the comment from the site, deleted
     /// will be true and comment will not be included.
-    deleted: bool,
+    pub deleted: bool,
     /// If the authenticated member has blocked the commenter, blocked will be
     /// true and comment will not be included.
-    blocked: bool,
+    pub blocked: bool,
     /// If the list owner has blocked the commenter, blockedByOwner will be
     /// true and comment will not be included.
-    blocked_by_owner: bool,
+    pub blocked_by_owner: bool,
     /// If the authenticated member posted this comment, and the comment is
     /// still editable, this value shows the number of seconds remaining until
     /// the editing window closes.
-    editable_window_expires_in: Option<usize>,
+    pub editable_window_expires_in: Option<usize>,
     /// The list on which the comment was posted.
-    list: ListIdentifier,
+    pub list: ListIdentifier,
     /// The message portion of the comment formatted as HTML.
-    comment: String,
+    pub comment: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ListCommentsResponse {
+pub struct ListCommentsResponse {
     /// The cursor to the next page of results.
-    next: Option<Cursor>,
+    pub next: Option<Cursor>,
     /// The list of comments.
-    items: Vec<ListComment>,
+    pub items: Vec<ListComment>,
 }
 
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ListCreateEntry {
     /// The LID of the film.
-    film: String,
+    film: FilmId,
     /// The entry’s rank in the list, numbered from 1. If not set, the entry
     /// will be appended to the end of the list. Sending two or more
     /// ListCreateEntrys with the same rank will return an error.
@@ -1281,7 +1328,7 @@ pub struct ListCreationRequest {
     description: Option<String>,
     /// The LID of a list to clone from. Only supported for paying members.
     #[serde(skip_serializing_if = "Option::is_none")]
-    cloned_from: Option<String>,
+    cloned_from: Option<ListId>,
     // The tags for the list.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     tags: Vec<String>,
@@ -1353,7 +1400,7 @@ pub struct ListEntriesRequest {
     pub where_film_status: Vec<FilmStatus>,
     /// Specify the LID of a member to limit the returned films according to
     /// the value set in memberRelationship.
-    pub member: Option<String>,
+    pub member: Option<MemberId>,
     /// Must be used in conjunction with member. Defaults to Watched. Specify
     /// the type of relationship to limit the returned films accordingly.
     pub member_relationship: Option<FilmRelationshipType>,
@@ -1367,7 +1414,7 @@ pub struct ListEntriesRequest {
     pub tag_code: Option<String>,
     /// Must be used with tag. Specify the LID of a member to focus the tag
     /// filter on the member.
-    pub tagger: Option<String>,
+    pub tagger: Option<MemberId>,
     /// Must be used in conjunction with tagger. Defaults to None, which
     /// filters tags set by the member. Use Only to filter tags set by the
     /// member’s friends, and All to filter tags set by both the member and
@@ -1412,32 +1459,32 @@ pub struct ListEntrySummary {
 #[derive(Deserialize, Debug, Clone)]
 pub struct ListIdentifier {
     /// The LID of the list.
-    pub id: String,
+    pub id: ListId,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct ListRelationship {
+pub struct ListRelationship {
     /// Will be true if the member likes the list (via the ‘heart’ icon). A
     /// member may not like their own list.
-    liked: bool,
+    pub liked: bool,
     /// Will be true if the member is subscribed to comment notifications for
     /// the list
-    subscribed: bool,
+    pub subscribed: bool,
     /// Defaults to Subscribed for the list’s owner, and NotSubscribed for
     /// other members. The subscription value may change when a member (other
     /// than the owner) posts a comment, as follows: the member will become
     /// automatically Subscribed unless they have previously Unsubscribed from
     /// the comment thread via the web interface or API, or unless they have
     /// disabled comment notifications in their profile settings.
-    subscription_state: SubscriptionState,
+    pub subscription_state: SubscriptionState,
     /// The authenticated member’s state with respect to adding comments for
     /// this list.
-    comment_thread_state: CommentThreadState,
+    pub comment_thread_state: CommentThreadState,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-enum ListRelationshipUpdateMessageCode {
+pub enum ListRelationshipUpdateMessageCode {
     LikeBlockedContent,
     LikeOwnList,
     SubscribeWhenOptedOut,
@@ -1447,7 +1494,7 @@ enum ListRelationshipUpdateMessageCode {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
-enum ListRelationshipUpdateMessage {
+pub enum ListRelationshipUpdateMessage {
     Error {
         /// The error message code.
         code: ListRelationshipUpdateMessageCode,
@@ -1457,44 +1504,46 @@ enum ListRelationshipUpdateMessage {
     Success,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-struct ListRelationshipUpdateRequest {
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ListRelationshipUpdateRequest {
     /// Set to true if the member likes the list (via the ‘heart’ icon). A
     /// member may not like their own list.
-    liked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liked: Option<bool>,
     /// Set to true to subscribe the member to comment notifications for the list, or false to unsubscribe them. A value of true will be ignored if the member has disabled comment notifications in their profile settings.
-    subscribed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribed: Option<bool>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ListRelationshipUpdateResponse {
+pub struct ListRelationshipUpdateResponse {
     /// The response object.
-    data: ListRelationship,
+    pub data: ListRelationship,
     /// A list of messages the API client should show to the user.
-    messages: Vec<ListRelationshipUpdateMessage>,
+    pub messages: Vec<ListRelationshipUpdateMessage>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ListStatistics {
+pub struct ListStatistics {
     /// The list for which statistics were requested.
-    list: ListIdentifier,
+    pub list: ListIdentifier,
     /// The number of comments and likes for the list.
-    counts: ListStatisticsCounts,
+    pub counts: ListStatisticsCounts,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ListStatisticsCounts {
+pub struct ListStatisticsCounts {
     /// The number of comments for the list.
-    comments: usize,
+    pub comments: usize,
     /// The number of members who like the list.
-    likes: usize,
+    pub likes: usize,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ListSummary {
     /// The LID of the list.
-    pub id: String,
+    pub id: ListId,
     /// The name of the list.
     pub name: String,
     /// The number of films in the list.
@@ -1527,7 +1576,7 @@ pub struct ListSummary {
 #[serde(rename_all = "camelCase")]
 pub struct ListUpdateEntry {
     /// The LID of the film.
-    pub film: String,
+    pub film: FilmId,
     /// The entry’s rank in the list, numbered from 1. If not set, the entry
     /// will stay in the same place (if already in the list) or be appended to
     /// the end of the list (if not in the list). If set, any entries at or
@@ -1547,7 +1596,7 @@ pub struct ListUpdateEntry {
 }
 
 impl ListUpdateEntry {
-    pub fn new(film: String) -> ListUpdateEntry {
+    pub fn new(film: FilmId) -> ListUpdateEntry {
         ListUpdateEntry {
             film,
             rank: None,
@@ -1604,7 +1653,7 @@ pub struct ListUpdateRequest {
     pub tags: Vec<String>,
     /// Specify the LIDs of films to be removed from the list.
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub films_to_remove: Vec<String>,
+    pub films_to_remove: Vec<FilmId>,
     /// The specified entries will be inserted/appended to the list if they are
     /// not already present, or updated if they are present.
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -1686,16 +1735,16 @@ pub struct ListsRequest {
     /// signed-in member’s friends.
     pub sort: Option<ListRequestSort>,
     /// Specify the LID of a film to return lists that include that film.
-    pub film: Option<String>,
+    pub film: Option<FilmId>,
     /// Specify the LID of a list to return lists that were cloned from that
     /// list.
-    pub cloned_from: Option<String>,
+    pub cloned_from: Option<ListId>,
     /// Specify a tag code to limit the returned lists to those tagged
     /// accordingly. Must be used with member and memberRelationship=Owner.
     pub tag_code: Option<String>,
     /// Specify the LID of a member to return lists that are owned or liked by
     /// the member (or their friends, when used with includeFriends).
-    pub member: Option<String>,
+    pub member: Option<MemberId>,
     /// Must be used in conjunction with member. Defaults to Owner, which
     /// returns lists owned by the specified member. Use Liked to return lists
     /// liked by the member.
@@ -1720,13 +1769,13 @@ pub struct ListsRequest {
 #[derive(Deserialize, Debug, Clone)]
 pub struct ListsResponse {
     /// The cursor to the next page of results.
-    next: Option<Cursor>,
+    pub next: Option<Cursor>,
     /// The list of lists.
-    items: Vec<ListSummary>,
+    pub items: Vec<ListSummary>,
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum LogEntriesRequestSort {
+pub enum LogEntriesRequestSort {
     WhenAdded,
     Date,
     RatingHighToLow,
@@ -1755,13 +1804,13 @@ enum LogEntriesRequestSort {
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum LogEntryRelationshipType {
+pub enum LogEntryRelationshipType {
     Owner,
     Liked,
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum LogEntryStatus {
+pub enum LogEntryStatus {
     HasDiaryDate,
     HasReview,
     Clean,
@@ -1779,16 +1828,17 @@ enum LogEntryStatus {
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum LogEntryFilter {
+pub enum LogEntryFilter {
     NoDuplicateMembers,
 }
 
-#[derive(Serialize, Debug, Clone)]
-struct LogEntriesRequest {
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntriesRequest {
     /// The pagination cursor.
-    cursor: Option<Cursor>,
+    pub cursor: Option<Cursor>,
     /// The number of items to include per page (default is 20, maximum is 100).
-    per_page: Option<usize>,
+    pub per_page: Option<usize>,
     /// The order in which the log entries should be returned. Defaults to
     /// WhenAdded, which orders by creation date, unless you specify
     /// where=HasDiaryDate in which case the default is Date.
@@ -1802,75 +1852,75 @@ struct LogEntriesRequest {
     /// You may not specify a film when using ReleaseDateLatestFirst,
     /// ReleaseDateEarliestFirst, FilmName, FilmDurationShortestFirst,
     /// FilmDurationLongestFirst, or any of the FilmPopularity options.
-    sort: LogEntriesRequestSort,
+    pub sort: Option<LogEntriesRequestSort>,
     /// Specify the LID of a film to return log entries for that film. Must not
     /// be included if the sort value is ReleaseDateLatestFirst,
     /// ReleaseDateEarliestFirst, FilmName, FilmDurationShortestFirst,
     /// FilmDurationLongestFirst, or any of the FilmPopularity options.
-    film: String,
+    pub film: Option<FilmId>,
     /// Specify the LID of a member to limit the returned log entries according
     /// to the value set in memberRelationship.
-    member: String,
+    pub member: Option<MemberId>,
     /// Must be used in conjunction with member. Use Owner to limit the
     /// returned log entries to those created by the specified member. Use
     /// Liked to limit the returned reviews to those liked by the specified
     /// member (implies where=HasReview).
-    member_relationship: LogEntryRelationshipType,
+    pub member_relationship: Option<LogEntryRelationshipType>,
     /// Must be used in conjunction with member. Specify the type of
     /// relationship to limit the returned films accordingly. e.g. Use Liked to
     /// limit the returned reviews to those for films liked by the member.
-    film_member_relationship: FilmRelationshipType,
+    pub film_member_relationship: Option<FilmRelationshipType>,
     /// Must be used in conjunction with member. Defaults to None, which only
     /// returns log entries created or liked by the member. Use Only to return
     /// log entries created or liked by the member’s friends, and All to return
     /// log entries created or liked by both the member and their friends.
-    include_friends: IncludeFriends,
+    pub include_friends: Option<IncludeFriends>,
     /// If set, limits the returned log entries to those with date that falls
     /// during the specified year.
-    year: u16,
+    pub year: Option<u16>,
     /// Accepts values of 1 through 12. Must be used with year. If set, limits
     /// the returned log entries to those with a date that falls during the
     /// specified month and year.
-    month: u16,
+    pub month: Option<u16>,
     /// Accepts values of 1 through 52. Must be used with year. If set, limits
     /// the returned log entries to those with a date that falls during the
     /// specified week and year.
-    week: u16,
+    pub week: Option<u16>,
     /// Accepts values of 1 through 31. Must be used with month and year. If
     /// set, limits the returned log entries to those with a date that falls on
     /// the specified day, month and year.
-    day: u16,
+    pub day: Option<u16>,
     /// Allowable values are between 0.5 and 5.0, with increments of 0.5. If
     /// set, limits the returned log entries to those with a rating equal to or
     /// higher than the specified rating.
-    min_rating: f32,
+    pub min_rating: Option<f32>,
     /// Allowable values are between 0.5 and 5.0, with increments of 0.5. If
     /// set, limits the returned log entries to those with a rating equal to or
     /// lower than the specified rating.
-    max_rating: f32,
+    pub max_rating: Option<f32>,
     /// Specify the starting year of a decade (must end in 0) to limit films to
     /// those released during the decade. 1990
-    film_decade: u16,
+    pub film_decade: Option<u16>,
     /// Specify a year to limit films to those released during that year. 1994
-    film_year: u16,
+    pub film_year: Option<u16>,
     /// The LID of the genre. If set, limits the returned log entries to those
     /// for films that match the specified genre.
-    genre: String,
+    pub genre: Option<String>,
     /// Specify a tag code to limit the returned log entries to those tagged
     /// accordingly.
-    tag_code: String,
+    pub tag_code: Option<String>,
     /// Must be used with tag. Specify the LID of a member to focus the tag
     /// filter on the member.
-    tagger: String,
+    pub tagger: Option<MemberId>,
     /// Must be used in conjunction with tagger. Defaults to None, which
     /// filters tags set by the member. Use Only to filter tags set by the
     /// member’s friends, and All to filter tags set by both the member and
     /// their friends.
-    include_tagger_friends: IncludeFriends,
+    pub include_tagger_friends: Option<IncludeFriends>,
     /// Specify the ID of a supported service to limit films to those available
     /// from that service. The list of available services can be found by using
     /// the /films/film-services endpoint.
-    service: String,
+    pub service: Option<String>,
     /// Specify one or more values to limit the returned log entries
     /// accordingly. All values except HasDiaryDate, HasReview, Clean and
     /// NoSpoilers refer to properties of the associated film rather than to
@@ -1881,25 +1931,25 @@ struct LogEntriesRequest {
     /// exclude reviews where the owner has indicated that the review text
     /// contains plot spoilers for the film. where=Clean&where=NoSpoilers
     #[serde(rename = "where")]
-    where_logentry_status: Vec<LogEntryStatus>,
+    pub where_logentry_status: Vec<LogEntryStatus>,
     /// Specify NoDuplicateMembers to return only the first log entry for each
     /// member. filter=NoDuplicateMembers
-    filter: Vec<LogEntryFilter>,
+    pub filter: Vec<LogEntryFilter>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct LogEntriesResponse {
+pub struct LogEntriesResponse {
     /// The cursor to the next page of results.
-    next: Option<Cursor>,
+    pub next: Option<Cursor>,
     // The list of log entries.
-    items: Vec<LogEntry>,
+    pub items: Vec<LogEntry>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct LogEntry {
     /// The LID of the log entry.
-    pub id: String,
+    pub id: LogEntryId,
     /// A descriptive title for the log entry.
     pub name: String,
     /// The member who created the log entry.
@@ -1922,7 +1972,7 @@ pub struct LogEntry {
     pub when_updated: String,
     /// The member’s rating for the film. Allowable values are between 0.5 and
     /// 5.0, with increments of 0.5.
-    pub rating: f32,
+    pub rating: Option<f32>,
     /// Will be true if the member likes the film (via the ‘heart’ icon).
     pub like: bool,
     /// Will be true if the log entry can have comments.
@@ -1932,53 +1982,72 @@ pub struct LogEntry {
     pub links: Vec<Link>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct LogEntryCreationRequest {
+pub struct LogEntryCreationRequest {
     /// The film being logged.
-    film_id: String,
+    pub film_id: FilmId,
     /// Information about this log entry if adding to the member’s diary.
-    diary_details: LogEntryCreationRequestDiaryDetails,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diary_details: Option<LogEntryCreationRequestDiaryDetails>,
     /// Information about the review if adding a review.
-    review: LogEntryCreationRequestReview,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review: Option<LogEntryCreationRequestReview>,
     ///  The tags for the log entry.
-    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     /// Allowable values are between 0.5 and 5.0, with increments of 0.5.
-    rating: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<f32>,
     /// Set to true if the member likes the film (via the ‘heart’ icon).
-    like: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub like: Option<bool>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl LogEntryCreationRequest {
+    pub fn new(film_id: FilmId) -> LogEntryCreationRequest {
+        LogEntryCreationRequest {
+            film_id,
+            diary_details: None,
+            review: None,
+            tags: Vec::new(),
+            rating: None,
+            like: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct LogEntryCreationRequestDiaryDetails {
+pub struct LogEntryCreationRequestDiaryDetails {
     /// The date the film was watched, if specified, in ISO 8601 format, i.e.
     /// YYYY-MM-DD
-    diary_date: String,
+    pub diary_date: String,
     /// Set to true if the member has indicated (or it can be otherwise
     /// determined) that the member has seen the film prior to this date.
-    rewatch: bool,
+    pub rewatch: bool,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct LogEntryCreationRequestReview {
+pub struct LogEntryCreationRequestReview {
     /// The review text in LBML. May contain the following HTML tags: `<br>`
     /// `<strong>` `<em>` `<b>` `<i>` `<a href="">` `<blockquote>`. This field
     /// has a maximum size of 100,000 characters.
-    text: String,
+    pub text: String,
     /// Set to true if the member has indicated that the review field contains
     /// plot spoilers for the film.
-    contains_spoilers: bool,
+    pub contains_spoilers: bool,
     /// The third-party service or services to which this review should be
     /// shared. Valid options are found in the
     /// MemberAccount.authorizedSharingServicesForReviews (see the /me
     /// endpoint).
-    share: Vec<ThirdPartyService>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub share: Vec<ThirdPartyService>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-enum LogEntryUpdateMessageCode {
+pub enum LogEntryUpdateMessageCode {
     InvalidRatingValue,
     InvalidDiaryDate,
     ReviewWithNoText,
@@ -1988,7 +2057,7 @@ enum LogEntryUpdateMessageCode {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
-enum LogEntryUpdateMessage {
+pub enum LogEntryUpdateMessage {
     Error {
         /// The error message code
         code: LogEntryUpdateMessageCode,
@@ -1998,161 +2067,169 @@ enum LogEntryUpdateMessage {
     Success,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+/// The nullable fields use a nested `Option`: `Some(None)` is sent as null
+/// and removes the value from the log entry.
+#[derive(Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-struct LogEntryUpdateRequest {
+pub struct LogEntryUpdateRequest {
     /// Information about this log entry if adding to the member’s diary. Set
     /// to null to remove this log entry from the diary.
-    diary_details: LogEntryUpdateRequestDiaryDetails,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diary_details: Option<Option<LogEntryUpdateRequestDiaryDetails>>,
     /// Information about the review. Set to null to remove the review from
     /// this log entry.
-    review: LogEntryUpdateRequestReview,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review: Option<Option<LogEntryUpdateRequestReview>>,
     // The tags for the log entry.
-    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
     /// Accepts values between 0.5 and 5.0, with increments of 0.5, or null (to
     /// remove the rating).
-    rating: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<Option<f32>>,
     /// Set to true if the member likes the film (via the ‘heart’ icon).
-    like: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub like: Option<bool>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct LogEntryUpdateRequestDiaryDetails {
+pub struct LogEntryUpdateRequestDiaryDetails {
     /// The date the film was watched, if specified, in ISO 8601 format, i.e.
     /// YYYY-MM-DD
-    diary_date: String,
+    pub diary_date: String,
     /// Set to true if the member has indicated (or it can be otherwise
     /// determined) that the member has seen the film prior to this date.
-    rewatch: bool,
+    pub rewatch: bool,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct LogEntryUpdateRequestReview {
+pub struct LogEntryUpdateRequestReview {
     /// The review text in LBML. May contain the following HTML tags: `<br>`
     /// `<strong>` `<em>` `<b>` `<i>` `<a href="">` `<blockquote>`.
-    text: String,
+    pub text: String,
     /// Set to true if the member has indicated that the review field contains
     /// plot spoilers for the film.
-    contains_spoilers: bool,
+    pub contains_spoilers: bool,
     // The third-party service or services to which this review should be shared. Valid options are found in the ReviewRelationship.canShareOn (see the /log-entry/{id}/me endpoint).
-    share: Vec<ThirdPartyService>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub share: Vec<ThirdPartyService>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct Member {
+pub struct Member {
     /// The LID of the member.
-    id: String,
+    pub id: MemberId,
     /// The member’s Letterboxd username. Usernames must be between 2 and 15
     /// characters long and may only contain upper or lowercase letters,
     /// numbers or the underscore (_) character.
-    username: String,
+    pub username: String,
     /// The given name of the member.
-    given_name: String,
+    pub given_name: Option<String>,
     /// The family name of the member.
-    family_name: String,
+    pub family_name: Option<String>,
     /// A convenience method that returns the member’s given name and family
     /// name concatenated with a space, if both are set, or just their given
     /// name or family name, if one is set, or their username, if neither is
     /// set. Will never be empty.
-    display_name: String,
+    pub display_name: String,
     /// A convenience method that returns the member’s given name, if set, or
     /// their username. Will never be empty.
-    short_name: String,
+    pub short_name: String,
     /// The member’s preferred pronoun set. Use the /members/pronouns endpoint
     /// to request all available pronoun sets.
-    pronoun: Pronoun,
+    pub pronoun: Pronoun,
     /// The member’s Twitter username, if they have authenticated their account.
-    twitter_username: String,
+    pub twitter_username: Option<String>,
     /// The member’s bio in LBML. May contain the following HTML tags: `<br>`
     /// `<strong>` `<em>` `<b>` `<i>` `<a href="">` `<blockquote>`.
-    bio_lbml: String,
+    pub bio_lbml: Option<String>,
     /// The member’s location.
-    location: String,
+    pub location: Option<String>,
     /// The member’s website URL. URLs are not validated, so sanitizing may be
     /// required.
-    website: String,
+    pub website: Option<String>,
     /// The member’s avatar image at multiple sizes.
-    avatar: Image,
+    pub avatar: Image,
     /// The member’s backdrop image at multiple sizes, sourced from the first
     /// film in the member’s list of favorite films, if available. Only
     /// returned for Patron members.
-    backdrop: Image,
+    pub backdrop: Option<Image>,
     /// The vertical focal point of the member’s backdrop image, if available.
     /// Expressed as a proportion of the image’s height, using values between
     /// 0.0 and 1.0. Use when cropping the image into a shorter space, such as
     /// in the page for a film on the Letterboxd site.
-    backdrop_focal_point: f32,
+    pub backdrop_focal_point: Option<f32>,
     /// The member’s account type.
-    member_status: MemberStatus,
+    pub member_status: MemberStatus,
     /// A summary of the member’s favorite films, up to a maximum of four.
-    favorite_films: Vec<FilmSummary>,
+    pub favorite_films: Vec<FilmSummary>,
     /// A link to the member’s profile page on the Letterboxd website.
-    links: Vec<Link>,
+    pub links: Vec<Link>,
     /// The member’s bio formatted as HTML.
-    bio: String,
+    pub bio: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct MemberAccount {
+pub struct MemberAccount {
     /// The member’s email address.
-    email_address: String,
+    pub email_address: String,
     /// Will be true if the member has validated their emailAddress via an
     /// emailed link.
-    email_address_validated: bool,
+    pub email_address_validated: bool,
     /// Defaults to false for new accounts. Indicates whether the member has
     /// elected for their content to appear in the API (other than in the /me
     /// endpoint).
-    private_account: bool,
+    pub private_account: bool,
     /// Defaults to true for new accounts. Indicates whether the member has
     /// elected to appear in the People section of the Letterboxd website.
-    include_in_people_section: bool,
+    pub include_in_people_section: bool,
     /// Defaults to false for new accounts. Indicates whether the member has
     /// elected to hide their Watchlist from other members.
-    private_watchlist: bool,
+    pub private_watchlist: bool,
     /// Defaults to true for new accounts. Indicates whether the member has elected to receive email notifications when they receive a new follower.
-    email_when_followed: bool,
+    pub email_when_followed: bool,
     /// Defaults to true for new accounts. Indicates whether the member has
     /// elected to receive email notifications when new comments are posted in
     /// threads they are subscribed to.
-    email_comments: bool,
+    pub email_comments: bool,
     /// Defaults to true for new accounts. Indicates whether the member has
     /// elected to receive regular email news (including ‘Call Sheet’) from
     /// Letterboxd.
-    email_news: bool,
+    pub email_news: bool,
     /// Defaults to true for new accounts. Indicates whether the member has
     /// elected to receive a weekly email digest of new and popular content
     /// (called ‘Rushes’).
-    email_rushes: bool,
+    pub email_rushes: bool,
     /// Defaults to false for new accounts. Indicates whether the member has
     /// commenting privileges. Commenting is disabled on new accounts until the
     /// member’s emailAddress is validated. At present canComment is synonymous
     /// with emailAddressValidated (unless the member is suspended) but this
     /// may change in future.
-    can_comment: bool,
+    pub can_comment: bool,
     /// Indicates whether the member is suspended from commenting due to a
     /// breach of the Community Policy.
-    suspended: bool,
+    pub suspended: bool,
     /// Indicates whether the member is able to clone other members’ lists.
     /// Determined by Letterboxd based upon memberStatus.
-    can_clone_lists: bool,
+    pub can_clone_lists: bool,
     /// Indicates whether the member is able to filter activity by type.
     /// Determined by Letterboxd based upon memberStatus.
-    can_filter_activity: bool,
+    pub can_filter_activity: bool,
     /// The services the member has authorized Letterboxd to share lists to.
     /// More services may be added in the future.
-    authorized_sharing_services_for_lists: Vec<ThirdPartyService>,
+    pub authorized_sharing_services_for_lists: Vec<ThirdPartyService>,
     /// The services the member has authorized Letterboxd to share reviews to.
     /// More services may be added in the future.
-    authorized_sharing_services_for_reviews: Vec<ThirdPartyService>,
+    pub authorized_sharing_services_for_reviews: Vec<ThirdPartyService>,
     /// The number of days the member has left in their subscription. Only
     /// returned for paying members.
-    membership_days_remaining: usize,
+    pub membership_days_remaining: Option<usize>,
     /// Standard member details.
-    member: Member,
+    pub member: Member,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -2209,7 +2286,7 @@ pub struct MemberFilmRelationshipsRequest {
     pub sort: Option<MemberFilmRelationshipsRequestSort>,
     /// Specify the LID of a member to return members who follow or are
     /// followed by that member.
-    pub member: Option<String>,
+    pub member: Option<MemberId>,
     /// Must be used in conjunction with member. Defaults to IsFollowing, which
     /// returns the list of members followed by the member. Use IsFollowedBy to
     /// return the list of members that follow the member.
@@ -2223,36 +2300,36 @@ pub struct MemberFilmRelationshipsRequest {
 #[derive(Deserialize, Debug, Clone)]
 pub struct MemberFilmRelationshipsResponse {
     /// The cursor to the next page of results.
-    pub next: Cursor,
+    pub next: Option<Cursor>,
     /// The list of film relationships for members.
     pub items: Vec<MemberFilmRelationship>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct MemberIdentifier {
+pub struct MemberIdentifier {
     /// The LID of the member.
-    id: String,
+    pub id: MemberId,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct MemberRelationship {
+pub struct MemberRelationship {
     /// Will be true if the authenticated member follows the member identified
     /// by ID.
-    following: bool,
+    pub following: bool,
     /// Will be true if the member identified by ID follows the authenticated
     /// member.
-    followed_by: bool,
+    pub followed_by: bool,
     /// Will be true if the authenticated member has blocked the member
     /// identified by ID.
-    blocking: bool,
+    pub blocking: bool,
     /// Will be true if the member identified by ID has blocked the
     /// authenticated member.
-    blocked_by: bool,
+    pub blocked_by: bool,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-enum MemberRelationshipUpdateMessageCode {
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberRelationshipUpdateMessageCode {
     BlockYourself,
     FollowYourself,
     FollowBlockedMember,
@@ -2261,7 +2338,7 @@ enum MemberRelationshipUpdateMessageCode {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
-enum MemberRelationshipUpdateMessage {
+pub enum MemberRelationshipUpdateMessage {
     Error {
         /// The error message code.
         code: MemberRelationshipUpdateMessageCode,
@@ -2271,29 +2348,66 @@ enum MemberRelationshipUpdateMessage {
     Success,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-struct MemberRelationshipUpdateRequest {
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct MemberRelationshipUpdateRequest {
     /// Set to true if the authenticated member wishes to follow the member
     /// identified by ID, or false if they wish to unfollow. A member may not
     /// follow their own account, or the account of a member they have blocked
     /// or that has blocked them.
-    following: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub following: Option<bool>,
     /// Set to true if the authenticated member wishes to block the member
     /// identified by ID, or false if they wish to unblock. A member may not
     /// block their own account.
-    blocking: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocking: Option<bool>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct MemberRelationshipUpdateResponse {
+pub struct MemberRelationshipUpdateResponse {
     /// The response object.
-    data: MemberRelationship,
+    pub data: MemberRelationship,
     /// A list of messages the API client should show to the user.
-    messages: Vec<MemberRelationshipUpdateMessage>,
+    pub messages: Vec<MemberRelationshipUpdateMessage>,
+}
+
+impl MemberRelationshipUpdateResponse {
+    /// Returns the updated relationship, or the first error message if the
+    /// update was rejected.
+    pub fn into_result(self) -> Result<MemberRelationship, MemberRelationshipUpdateError> {
+        let error = self.messages.into_iter().find_map(|message| match message {
+            MemberRelationshipUpdateMessage::Error { code, title } => {
+                Some(MemberRelationshipUpdateError { code, title })
+            }
+            MemberRelationshipUpdateMessage::Success => None,
+        });
+        match error {
+            Some(error) => Err(error),
+            None => Ok(self.data),
+        }
+    }
 }
 
+/// Error message of a rejected member relationship update, e.g. when trying
+/// to follow a blocked member.
+#[derive(Debug, Clone)]
+pub struct MemberRelationshipUpdateError {
+    /// The error message code.
+    pub code: MemberRelationshipUpdateMessageCode,
+    /// The error message text in human-readable form.
+    pub title: String,
+}
+
+impl fmt::Display for MemberRelationshipUpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.title)
+    }
+}
+
+impl std::error::Error for MemberRelationshipUpdateError {}
+
 #[derive(Deserialize, Debug, Clone)]
-enum MemberSettingsUpdateMessageCode {
+pub enum MemberSettingsUpdateMessageCode {
     IncorrectCurrentPassword,
     BlankPassword,
     InvalidEmailAddress,
@@ -2304,7 +2418,7 @@ enum MemberSettingsUpdateMessageCode {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
-enum MemberSettingsUpdateMessage {
+pub enum MemberSettingsUpdateMessage {
     Error {
         /// The error message code.
         code: MemberSettingsUpdateMessageCode,
@@ -2314,121 +2428,138 @@ enum MemberSettingsUpdateMessage {
     Success,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-struct MemberSettingsUpdateRequest {
+pub struct MemberSettingsUpdateRequest {
     /// The member’s email address.
-    email_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
     /// The member’s current password. Required when updating the password.
-    current_password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_password: Option<String>,
     /// The member’s new password.
-    password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
     /// The given name of the member.
-    given_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
     /// The family name of the member.
-    family_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
     /// The LID of the member’s preferred pronoun set. Use the
     /// /members/pronouns endpoint to request all available pronoun sets.
-    pronoun: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronoun: Option<String>,
     /// The member’s location.
-    location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
     /// The member’s website URL. URLs are not validated, so sanitizing may be
     /// required.
-    website: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
     /// The member’s bio in LBML. May contain the following HTML tags: `<br>`
     /// `<strong>` `<em>` `<b>` `<i>` `<a href="">` `<blockquote>`. This field
     /// has a maximum size of 100,000 characters.
-    bio: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
     /// The LIDs of the member’s favorite films, in order, up to a maximum of
     /// four.
-    favorite_films: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favorite_films: Option<Vec<FilmId>>,
     /// Set to true to prevent the member’s content from appearing in API
     /// requests other than the /me endpoint.
-    private_account: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_account: Option<bool>,
     /// Set to false to remove the account from the People section of the
     /// Letterboxd website.
-    include_in_people_section: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_in_people_section: Option<bool>,
     /// Set to true if the member wishes to receive email notifications when
     /// they receive a new follower.
-    email_when_followed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_when_followed: Option<bool>,
     /// Set to true if the member wishes to receive email notifications when
     /// new comments are posted in threads they are subscribed to.
-    email_comments: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_comments: Option<bool>,
     /// Set to true if the member wishes to receive regular email news
     /// (including ‘Call Sheet’) from Letterboxd.
-    email_news: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_news: Option<bool>,
     /// Set to true if the member wishes to receive a weekly email digest of
     /// new and popular content (called ‘Rushes’).
-    email_rushes: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_rushes: Option<bool>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct MemberSettingsUpdateResponse {
+pub struct MemberSettingsUpdateResponse {
     /// The response object.
-    data: MemberAccount,
+    pub data: MemberAccount,
     /// A list of messages the API client should show to the user.
-    messages: Vec<MemberSettingsUpdateMessage>,
+    pub messages: Vec<MemberSettingsUpdateMessage>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct MemberStatistics {
+pub struct MemberStatistics {
     /// The member for which statistics were requested.
-    member: MemberIdentifier,
+    pub member: MemberIdentifier,
     /// The number of watches, ratings, likes, etc. for the member.
-    counts: MemberStatisticsCounts,
+    pub counts: MemberStatisticsCounts,
     /// A summary of the number of ratings the member has made for each
     /// increment between 0.5 and 5.0. Returns only the integer increments
     /// between 1.0 and 5.0 if the member never (or rarely) awards half-star
     /// ratings.
-    ratings_histogram: Vec<RatingsHistogramBar>,
+    pub ratings_histogram: Vec<RatingsHistogramBar>,
     /// A list of years the member has year-in-review pages for. Only supported
     /// for paying members.
-    years_in_review: Vec<u16>,
+    #[serde(default)]
+    pub years_in_review: Vec<u16>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct MemberStatisticsCounts {
+pub struct MemberStatisticsCounts {
     /// The number of films the member has liked.
-    film_likes: usize,
+    pub film_likes: usize,
     /// The number of lists the member has liked.
-    list_likes: usize,
+    pub list_likes: usize,
     /// The number of reviews the member has liked.
-    review_likes: usize,
+    pub review_likes: usize,
     /// The number of films the member has watched. This is a distinct total —
     /// films with multiple log entries are only counted once.
-    watches: usize,
+    pub watches: usize,
     /// The number of films the member has rated.
-    ratings: usize,
+    pub ratings: usize,
     /// The number of films the member has reviewed.
-    reviews: usize,
+    pub reviews: usize,
     /// The number of entries the member has in their diary.
-    diary_entries: usize,
+    pub diary_entries: usize,
     /// The number of entries the member has in their diary for the current
     /// year. The current year rolls over at midnight on 31 December in New
     /// Zealand Daylight Time (GMT + 13).
-    diary_entries_this_year: usize,
+    pub diary_entries_this_year: usize,
     /// The number of unique films the member has in their diary for the
     /// current year. The current year rolls over at midnight on 31 December in
     /// New Zealand Daylight Time (GMT + 13).
-    films_in_diary_this_year: usize,
+    pub films_in_diary_this_year: usize,
     /// The number of films the member has in their watchlist.
-    watchlist: usize,
+    pub watchlist: usize,
     /// The number of lists for the member. Includes unpublished lists if the
     /// request is made for the authenticated member.
-    lists: usize,
+    pub lists: usize,
     /// The number of unpublished lists for the member. Only included if the
     /// request is made for the authenticated member.
-    unpublished_lists: usize,
+    pub unpublished_lists: Option<usize>,
     /// The number of members who follow the member.
-    followers: usize,
+    pub followers: usize,
     /// The number of members the member is following.
-    following: usize,
+    pub following: usize,
     /// The number of tags the member has used for lists.
-    list_tags: usize,
+    pub list_tags: usize,
     /// The number of tags the member has used for diary entries and reviews.
-    film_tags: usize,
+    pub film_tags: usize,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -2443,7 +2574,7 @@ pub enum MemberStatus {
 #[serde(rename_all = "camelCase")]
 pub struct MemberSummary {
     /// The LID of the member.
-    pub id: String,
+    pub id: MemberId,
     /// The member’s Letterboxd username. Usernames must be between 2 and 15
     /// characters long and
     /// may only contain upper or lowercase letters, numbers or the underscore
@@ -2473,45 +2604,51 @@ pub struct MemberSummary {
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct MemberTag {
+pub struct MemberTag {
     /// The tag code.
-    code: String,
+    pub code: String,
     /// The tag text as entered by the tagger.
-    display_tag: String,
+    pub display_tag: String,
     /// Counts of the member’s uses of this tag.
-    counts: MemberTagCounts,
+    pub counts: MemberTagCounts,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct MemberTagCounts {
+pub struct MemberTagCounts {
     /// The number of films the member has used this tag on.
-    films: usize,
+    pub films: usize,
     /// The number of log entries the member has used this tag on.
-    log_entries: usize,
+    pub log_entries: usize,
     /// The number of diary entries the member has used this tag on.
-    diary_entries: usize,
+    pub diary_entries: usize,
     /// The number of reviews the member has used this tag on.
-    reviews: usize,
+    pub reviews: usize,
     /// The number of lists the member has used this tag on.
-    lists: usize,
+    pub lists: usize,
 }
 
-#[derive(Clone, Debug, Serialize)]
-struct MemberTagsRequest {
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct MemberTagsRequest {
     /// A case-insensitive prefix match. E.g. “pro” will match “pro”, “project”
     /// and “Professional”. An empty input will match all tags.
-    input: String,
+    pub input: String,
+}
+
+impl MemberTagsRequest {
+    pub fn new(input: String) -> Self {
+        Self { input }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
-struct MemberTagsResponse {
+pub struct MemberTagsResponse {
     /// The list of tag items, ordered by frequency of use.
-    items: Vec<MemberTag>,
+    pub items: Vec<MemberTag>,
 }
 
 #[derive(Clone, Debug, Serialize)]
-enum MembersRequestSort {
+pub enum MembersRequestSort {
     Date,
     Name,
     MemberPopularity,
@@ -2526,18 +2663,18 @@ enum MembersRequestSort {
 
 // TODO: name
 #[derive(Clone, Debug, Serialize)]
-enum MembersRequestRelationship {
+pub enum MembersRequestRelationship {
     IsFollowing,
     IsFollowedBy,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
-struct MembersRequest {
+pub struct MembersRequest {
     /// The pagination cursor.
-    cursor: Option<Cursor>,
+    pub cursor: Option<Cursor>,
     /// The number of items to include per page (default is 20, maximum is 100).
-    per_page: Option<usize>,
+    pub per_page: Option<usize>,
     /// Defaults to Date, which has different semantics based on the request:
     /// When review is specified, members who most recently liked the review
     /// appear first.
@@ -2554,40 +2691,76 @@ struct MembersRequest {
     /// Otherwise, members who most recently joined the site appear first.
     /// The PopularWithFriends values are only available to authenticated
     /// members and consider popularity amongst the member’s friends.
-    sort: MembersRequestSort,
+    pub sort: Option<MembersRequestSort>,
     /// Specify the LID of a member to return members who follow or are
     /// followed by that member.
-    member: String,
+    pub member: Option<MemberId>,
     /// Must be used in conjunction with member. Defaults to IsFollowing, which
     /// returns the list of members followed by the member. Use IsFollowedBy to
     /// return the list of members that follow the member.
-    member_relationship: MembersRequestRelationship,
+    pub member_relationship: Option<MembersRequestRelationship>,
     /// Specify the LID of a film to return members who have interacted with
     /// that film.
-    film: String,
+    pub film: Option<FilmId>,
     /// Must be used in conjunction with film. Defaults to Watched, which
     /// returns the list of members who have seen the film. Specify the type of
     /// relationship to limit the returned members accordingly. You must
     /// specify a member in order to use the InWatchlist relationship.
-    film_relationship: FilmRelationship,
+    pub film_relationship: Option<FilmRelationshipType>,
     /// Specify the LID of a list to return members who like that list.
-    list: String,
+    pub list: Option<ListId>,
     /// Specify the LID of a review to return members who like that review.
-    review: String,
+    pub review: Option<LogEntryId>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
-struct MembersResponse {
+pub struct MembersResponse {
     /// The cursor to the next page of results.
-    next: Option<Cursor>,
+    pub next: Option<Cursor>,
     /// The list of members.
-    items: Vec<MemberSummary>,
+    pub items: Vec<MemberSummary>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
-struct OAuthError {
+pub struct OAuthError {
     /// The error code, usually invalid_grant.
-    error: String,
+    pub error: String,
+    /// Human readable description of the error.
+    pub error_description: Option<String>,
+}
+
+impl OAuthError {
+    /// The typed error code.
+    pub fn code(&self) -> OAuthErrorCode {
+        match self.error.as_str() {
+            "invalid_request" => OAuthErrorCode::InvalidRequest,
+            "invalid_client" => OAuthErrorCode::InvalidClient,
+            "invalid_grant" => OAuthErrorCode::InvalidGrant,
+            "unauthorized_client" => OAuthErrorCode::UnauthorizedClient,
+            "unsupported_grant_type" => OAuthErrorCode::UnsupportedGrantType,
+            "invalid_scope" => OAuthErrorCode::InvalidScope,
+            _ => OAuthErrorCode::Other,
+        }
+    }
+}
+
+/// Error codes of the `auth/token` endpoint as defined by OAuth 2.0 (RFC 6749).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OAuthErrorCode {
+    /// The request is missing a parameter or is otherwise malformed.
+    InvalidRequest,
+    /// The API key is invalid.
+    InvalidClient,
+    /// The credentials or the refresh token are invalid or expired.
+    InvalidGrant,
+    /// The API key may not use the grant type.
+    UnauthorizedClient,
+    /// The grant type is not supported.
+    UnsupportedGrantType,
+    /// The requested scope is invalid.
+    InvalidScope,
+    /// Any other error code, cf. `OAuthError::error`.
+    Other,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -2618,9 +2791,9 @@ pub struct Pronoun {
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct PronounsResponse {
+pub struct PronounsResponse {
     /// The list of pronouns.
-    items: Vec<Pronoun>,
+    pub items: Vec<Pronoun>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -2639,21 +2812,21 @@ pub struct RatingsHistogramBar {
 
 #[derive(Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
-struct RegisterRequest {
+pub struct RegisterRequest {
     /// The username for the new account. Use the /auth/username-check endpoint
     /// to check availability.
-    username: String,
+    pub username: String,
     /// The password for the new account.
-    password: String,
+    pub password: String,
     /// The email address for the new account.
-    email_address: String,
+    pub email_address: String,
     /// Set to true if the person creating the account has agreed to being at
     /// least 13 years of age, and to accepting Letterboxd’s Terms of Use.
-    accept_terms_of_use: bool,
+    pub accept_terms_of_use: bool,
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum ReportCommentReason {
+pub enum ReportCommentReason {
     Spoilers,
     Spam,
     Plagiarism,
@@ -2661,32 +2834,52 @@ enum ReportCommentReason {
 }
 
 #[derive(Serialize, Debug, Clone)]
-struct ReportCommentRequest {
-    ///  The reason why the comment was reported.
-    reason: ReportCommentReason,
+pub struct ReportCommentRequest {
+    /// The reason why the comment was reported.
+    pub reason: ReportCommentReason,
     /// An optional, explanatory message to accompany the report. Required if
     /// the reason is Plagiarism or Other.
-    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ReportCommentRequest {
+    pub fn new(reason: ReportCommentReason) -> Self {
+        Self {
+            reason,
+            message: None,
+        }
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum ReportFilmReason {
+pub enum ReportFilmReason {
     Duplicate,
     NotAFilm,
     Other,
 }
 
 #[derive(Serialize, Debug, Clone)]
-struct ReportFilmRequest {
+pub struct ReportFilmRequest {
     /// The reason why the film was reported.
-    reason: ReportFilmReason,
+    pub reason: ReportFilmReason,
     /// An optional, explanatory message to accompany the report. Required if
     /// the reason is Duplicate or Other.
-    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ReportFilmRequest {
+    pub fn new(reason: ReportFilmReason) -> Self {
+        Self {
+            reason,
+            message: None,
+        }
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum ReportListReason {
+pub enum ReportListReason {
     Spoilers,
     Spam,
     Plagiarism,
@@ -2694,31 +2887,51 @@ enum ReportListReason {
 }
 
 #[derive(Serialize, Debug, Clone)]
-struct ReportListRequest {
+pub struct ReportListRequest {
     /// The reason why the list was reported.
-    reason: ReportListReason,
+    pub reason: ReportListReason,
     /// An optional, explanatory message to accompany the report. Required if
     /// the reason is Plagiarism or Other.
-    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ReportListRequest {
+    pub fn new(reason: ReportListReason) -> Self {
+        Self {
+            reason,
+            message: None,
+        }
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum ReportMemberReason {
+pub enum ReportMemberReason {
     SpamAccount,
     Other,
 }
 
 #[derive(Serialize, Debug, Clone)]
-struct ReportMemberRequest {
+pub struct ReportMemberRequest {
     /// The reason why the member was reported.
-    reason: ReportMemberReason,
+    pub reason: ReportMemberReason,
     /// An optional, explanatory message to accompany the report. Required if
     /// the reason is Other.
-    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ReportMemberRequest {
+    pub fn new(reason: ReportMemberReason) -> Self {
+        Self {
+            reason,
+            message: None,
+        }
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
-enum ReportReviewReason {
+pub enum ReportReviewReason {
     Spoilers,
     Spam,
     Plagiarism,
@@ -2726,12 +2939,22 @@ enum ReportReviewReason {
 }
 
 #[derive(Serialize, Debug, Clone)]
-struct ReportReviewRequest {
+pub struct ReportReviewRequest {
     /// The reason why the review was reported.
-    reason: ReportReviewReason,
+    pub reason: ReportReviewReason,
     /// An optional, explanatory message to accompany the report. Required if
     /// the reason is Plagiarism or Other.
-    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ReportReviewRequest {
+    pub fn new(reason: ReportReviewReason) -> Self {
+        Self {
+            reason,
+            message: None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2764,60 +2987,60 @@ pub struct Review {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct ReviewComment {
+pub struct ReviewComment {
     /// The LID of the comment.
-    id: String,
+    pub id: CommentId,
     /// The member who posted the comment.
-    member: MemberSummary,
+    pub member: MemberSummary,
     /// ISO 8601 format with UTC timezone, i.e. YYYY-MM-DDThh:mm:ssZ
     /// "1997-08-29T07:14:00Z"
-    when_created: String,
+    pub when_created: String,
     /// ISO 8601 format with UTC timezone, i.e. YYYY-MM-DDThh:mm:ssZ
     /// "1997-08-29T07:14:00Z"
-    when_updated: String,
+    pub when_updated: String,
     /// The message portion of the comment in LBML. May contain the following
     /// HTML tags: `<br>` `<strong>` `<em>` `<b>` `<i>` `<a href="">`
     /// `<blockquote>`.
-    comment_lbml: String,
+    pub comment_lbml: Option<String>,
     /// If Letterboxd moderators have removed the comment from the site,
     /// removedByAdmin will be true and comment will not be included.
-    removed_by_admin: bool,
+    pub removed_by_admin: bool,
     /// If the comment owner has removed the comment from the site, deleted
     /// will be true and comment will not be included.
-    deleted: bool,
+    pub deleted: bool,
     /// If the authenticated member has blocked the commenter, blocked will be
     /// true and comment will not be included.
-    blocked: bool,
+    pub blocked: bool,
     /// If the review owner has blocked the commenter, blockedByOwner will be
     /// true and comment will not be included.
-    blocked_by_owner: bool,
+    pub blocked_by_owner: bool,
     /// If the authenticated member posted this comment, and the comment is
     /// still editable, this value shows the number of seconds remaining until
     /// the editing window closes.
-    editable_window_expires_in: Option<usize>,
+    pub editable_window_expires_in: Option<usize>,
     /// The review on which the comment was posted.
-    review: ReviewIdentifier,
+    pub review: ReviewIdentifier,
     /// The message portion of the comment formatted as HTML.
-    comment: String,
+    pub comment: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ReviewCommentsResponse {
+pub struct ReviewCommentsResponse {
     /// The cursor to the next page of results.
-    next: Option<Cursor>,
-    // The list of comments.
-    items: Vec<ReviewComment>,
+    pub next: Option<Cursor>,
+    /// The list of comments.
+    pub items: Vec<ReviewComment>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ReviewIdentifier {
+pub struct ReviewIdentifier {
     /// The LID of the log entry.
-    id: String,
+    pub id: LogEntryId,
 }
 
 // TODO: order
 #[derive(Deserialize, Debug, Clone)]
-enum CommentThreadState {
+pub enum CommentThreadState {
     /// `CanComment` means the authenticated member is authorized to add a
     /// comment. All other
     /// values mean the authenticated member is not authorized to add a comment.
@@ -2840,7 +3063,7 @@ enum CommentThreadState {
 /// if/when they post a
 /// comment.
 #[derive(Deserialize, Debug, Clone)]
-enum SubscriptionState {
+pub enum SubscriptionState {
     Subscribed,
     NotSubscribed,
     Unsubscribed,
@@ -2848,27 +3071,27 @@ enum SubscriptionState {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct ReviewRelationship {
+pub struct ReviewRelationship {
     /// Will be true if the member likes the review (via the ‘heart’ icon). A
     /// member may not like their own review.
-    liked: bool,
+    pub liked: bool,
     /// Will be true if the member is subscribed to comment notifications for
     /// the review
-    subscribed: bool,
+    pub subscribed: bool,
     /// Defaults to Subscribed for the review’s author, and NotSubscribed for
     /// other members. The subscription value may change when a member (other
     /// than the owner) posts a comment, as follows: the member will become
     /// automatically Subscribed unless they have previously Unsubscribed from
     /// the comment thread via the web interface or API, or unless they have
     /// disabled comment notifications in their profile settings.
-    subscription_state: SubscriptionState,
+    pub subscription_state: SubscriptionState,
     /// The authenticated member’s state with respect to adding comments for
     /// this review.
-    comment_thread_state: CommentThreadState,
+    pub comment_thread_state: CommentThreadState,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-enum ReviewRelationshipUpdateMessageCode {
+pub enum ReviewRelationshipUpdateMessageCode {
     LikeBlockedContent,
     LikeOwnReview,
     LikeLogEntryWithoutReview,
@@ -2879,7 +3102,7 @@ enum ReviewRelationshipUpdateMessageCode {
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
-enum ReviewRelationshipUpdateMessage {
+pub enum ReviewRelationshipUpdateMessage {
     Error {
         /// The error message code.
         code: ReviewRelationshipUpdateMessageCode,
@@ -2889,49 +3112,51 @@ enum ReviewRelationshipUpdateMessage {
     Success,
 }
 
-#[derive(Serialize, Debug, Clone)]
-struct ReviewRelationshipUpdateRequest {
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ReviewRelationshipUpdateRequest {
     /// Set to true if the member likes the review (via the ‘heart’ icon). A
     /// member may not like their own review.
-    liked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liked: Option<bool>,
     /// Set to true to subscribe the member to comment notifications for the
     /// review, or false to unsubscribe them. A value of true will be ignored
     /// if the member has disabled comment notifications in their profile
     /// settings.
-    subscribed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribed: Option<bool>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ReviewRelationshipUpdateResponse {
+pub struct ReviewRelationshipUpdateResponse {
     /// The response object.
-    data: ReviewRelationship,
+    pub data: ReviewRelationship,
     /// A list of messages the API client should show to the user.
-    messages: Vec<ReviewRelationshipUpdateMessage>,
+    pub messages: Vec<ReviewRelationshipUpdateMessage>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-struct ReviewStatistics {
+pub struct ReviewStatistics {
     /// The log entry for which statistics were requested.
-    log_entry: ReviewIdentifier,
+    pub log_entry: ReviewIdentifier,
     /// The number of comments and likes for the review.
-    counts: ReviewStatisticsCounts,
+    pub counts: ReviewStatisticsCounts,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ReviewStatisticsCounts {
+pub struct ReviewStatisticsCounts {
     /// The number of comments for the review.
-    comments: usize,
+    pub comments: usize,
     /// The number of members who like the review.
-    likes: usize,
+    pub likes: usize,
 }
 
 #[derive(Deserialize, Debug, Clone)]
-struct ReviewUpdateResponse {
+pub struct ReviewUpdateResponse {
     /// The response object.
-    data: LogEntry,
+    pub data: LogEntry,
     /// A list of messages the API client should show to the user.
-    messages: Vec<LogEntryUpdateMessage>,
+    pub messages: Vec<LogEntryUpdateMessage>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -3015,8 +3240,8 @@ struct TagsResponse {
     items: Vec<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-enum UsernameCheckResult {
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsernameCheckResult {
     Available,
     NotAvailable,
     TooShort,
@@ -3024,15 +3249,21 @@ enum UsernameCheckResult {
     Invalid,
 }
 
+#[derive(Serialize, Debug, Clone)]
+pub(crate) struct UsernameCheckRequest<'a> {
+    /// The username to check.
+    pub username: &'a str,
+}
+
 #[derive(Deserialize, Debug, Clone)]
-struct UsernameCheckResponse {
+pub(crate) struct UsernameCheckResponse {
     /// Will be Available if the username is available to register, or
     /// NotAvailable if used by another member (or attached to a deactivated
     /// account, or otherwise reserved). May return an appropriate error value
     /// if the username doesn’t meet Letterboxd’s requirements: Usernames must
     /// be between 2 and 15 characters long and may only contain upper or
     /// lowercase letters, numbers or the underscore (_) character.
-    result: UsernameCheckResult,
+    pub result: UsernameCheckResult,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -3059,55 +3290,246 @@ pub enum IncludeFriends {
     Only,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-struct WatchlistRequest {
+pub struct WatchlistRequest {
     /// The pagination cursor.
-    cursor: Option<Cursor>,
+    pub cursor: Option<Cursor>,
     /// The number of items to include per page (default is 20, maximum is 100).
-    per_page: Option<usize>,
+    pub per_page: Option<usize>,
     /// The order in which the entries should be returned. Defaults to Added,
     /// which is the order that the films were added to the watchlist, most
     /// recent first.
-    sort: WatchlistSort,
+    pub sort: Option<WatchlistSort>,
     /// Specify the LID of a genre to limit films to those within the specified
     /// genre.
-    genre: String,
+    pub genre: Option<String>,
     /// Specify the starting year of a decade (must end in 0) to limit films to
     /// those released during the decade. 1990
-    decade: u16,
+    pub decade: Option<u16>,
     /// Specify a year to limit films to those released during that year. 1994
-    year: u16,
+    pub year: Option<u16>,
     /// Specify the ID of a supported service to limit films to those available
     /// from that service. The list of available services can be found by using
     /// the /films/film-services endpoint.
-    service: String,
+    pub service: Option<String>,
     /// Specify one or more values to limit the list of films accordingly.
     /// where=Watched&where=Released
     #[serde(rename = "where")]
-    where_film_status: Vec<FilmStatus>,
+    pub where_film_status: Vec<FilmStatus>,
     /// Specify the LID of a member to limit the returned films according to
     /// the value set in memberRelationship. The member and memberRelationship
     /// parameters can be used to compute comparisons between the watchlist
     /// owner and another member.
-    member: String,
+    pub member: Option<MemberId>,
     /// Must be used in conjunction with member. Defaults to Watched. Specify
     /// the type of relationship to limit the returned films accordingly.
-    member_relationship: FilmRelationshipType,
+    pub member_relationship: Option<FilmRelationshipType>,
     /// Must be used in conjunction with member. Defaults to None, which only
     /// returns films from the member’s account. Use Only to return films from
     /// the member’s friends, and All to return films from both the member and
     /// their friends.
-    include_friends: IncludeFriends,
+    pub include_friends: Option<IncludeFriends>,
     /// Specify a tag code to limit the returned films to those tagged
     /// accordingly.
-    tag_code: String,
+    pub tag_code: Option<String>,
     /// Must be used with tag. Specify the LID of a member to focus the tag
     /// filter on the member.
-    tagger: String,
+    pub tagger: Option<MemberId>,
     /// Must be used in conjunction with tagger. Defaults to None, which
     /// filters tags set by the member. Use Only to filter tags set by the
     /// member’s friends, and All to filter tags set by both the member and
     /// their friends.
-    include_tagger_friends: IncludeFriends,
+    pub include_tagger_friends: Option<IncludeFriends>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_token_records_issue_time() {
+        let json = r#"{
+            "access_token": "access",
+            "token_type": "bearer",
+            "refresh_token": "refresh",
+            "expires_in": 3600
+        }"#;
+        let token: AccessToken = serde_json::from_str(json).unwrap();
+        assert!(token.issued_at > 0);
+        assert!(!token.is_expired());
+
+        let token = AccessToken {
+            issued_at: token.issued_at - 3600,
+            ..token
+        };
+        assert!(token.is_expired());
+    }
+
+    #[test]
+    fn comment_update_response() {
+        let json = r#"{
+            "data": {
+                "type": "ListComment",
+                "id": "1a2b",
+                "member": {
+                    "id": "11Ht",
+                    "username": "dave",
+                    "displayName": "Dave Vis",
+                    "shortName": "Dave",
+                    "pronoun": {
+                        "id": "1",
+                        "label": "He / his",
+                        "subjectPronoun": "he",
+                        "objectPronoun": "him",
+                        "possessiveAdjective": "his",
+                        "possessivePronoun": "his",
+                        "reflexive": "himself"
+                    },
+                    "avatar": { "sizes": [] },
+                    "memberStatus": "Crew"
+                },
+                "whenCreated": "1997-08-29T07:14:00Z",
+                "whenUpdated": "1997-08-29T07:14:00Z",
+                "commentLbml": "Great list",
+                "removedByAdmin": false,
+                "deleted": false,
+                "blocked": false,
+                "blockedByOwner": false,
+                "list": { "id": "p7Xk" },
+                "comment": "<p>Great list</p>"
+            },
+            "messages": [
+                {
+                    "type": "Error",
+                    "code": "CommentEditWindowExpired",
+                    "title": "The comment can no longer be edited."
+                }
+            ]
+        }"#;
+        let resp: CommentUpdateResponse = serde_json::from_str(json).unwrap();
+        match resp.data {
+            AbstractComment::ListComment {
+                comment_lbml, list, ..
+            } => {
+                assert_eq!(comment_lbml.as_deref(), Some("Great list"));
+                assert_eq!(list.id, "p7Xk");
+            }
+            comment => panic!("unexpected comment {:?}", comment),
+        }
+        assert!(matches!(
+            resp.messages[..],
+            [CommentUpdateMessage::Error {
+                code: CommentUpdateMessageCode::CommentEditWindowExpired,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn member_relationship_update_result() {
+        let json = r#"{
+            "data": {
+                "following": false,
+                "followedBy": false,
+                "blocking": true,
+                "blockedBy": false
+            },
+            "messages": [
+                {
+                    "type": "Error",
+                    "code": "FollowMemberYouBlocked",
+                    "title": "You cannot follow a member you have blocked."
+                }
+            ]
+        }"#;
+        let resp: MemberRelationshipUpdateResponse = serde_json::from_str(json).unwrap();
+        let err = resp.into_result().unwrap_err();
+        assert_eq!(
+            err.code,
+            MemberRelationshipUpdateMessageCode::FollowMemberYouBlocked
+        );
+
+        let json = r#"{
+            "data": {
+                "following": true,
+                "followedBy": false,
+                "blocking": false,
+                "blockedBy": false
+            },
+            "messages": [{ "type": "Success" }]
+        }"#;
+        let resp: MemberRelationshipUpdateResponse = serde_json::from_str(json).unwrap();
+        assert!(resp.into_result().unwrap().following);
+    }
+
+    #[test]
+    fn contributor_search_item_without_statistics() {
+        let json = r#"{
+            "items": [
+                {
+                    "type": "ContributorSearchItem",
+                    "score": 42.0,
+                    "contributor": {
+                        "id": "2Bx",
+                        "name": "David Fincher",
+                        "links": []
+                    }
+                }
+            ]
+        }"#;
+        let resp: SearchResponse = serde_json::from_str(json).unwrap();
+        match resp.items[..] {
+            [AbstractSearchItem::ContributorSearchItem {
+                ref contributor, ..
+            }] => {
+                assert_eq!(contributor.id, "2Bx");
+                assert!(contributor.statistics.is_none());
+            }
+            ref items => panic!("unexpected items {:?}", items),
+        }
+    }
+
+    #[test]
+    fn unknown_activity_is_tolerated() {
+        let json = r#"{
+            "next": "start=1",
+            "items": [
+                {
+                    "type": "RegistrationActivity",
+                    "whenCreated": "1997-08-29T07:14:00Z",
+                    "member": {
+                        "id": "11Ht",
+                        "username": "dave",
+                        "displayName": "Dave Vis",
+                        "shortName": "Dave",
+                        "pronoun": {
+                            "id": "1",
+                            "label": "He / his",
+                            "subjectPronoun": "he",
+                            "objectPronoun": "him",
+                            "possessiveAdjective": "his",
+                            "possessivePronoun": "his",
+                            "reflexive": "himself"
+                        },
+                        "avatar": { "sizes": [] },
+                        "memberStatus": "Crew"
+                    }
+                },
+                {
+                    "type": "SomeFutureActivity",
+                    "whenCreated": "1997-08-29T07:14:00Z",
+                    "something": { "id": "2a9q" }
+                }
+            ]
+        }"#;
+        let resp: ActivityResponse = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            resp.items[..],
+            [
+                AbstractActivity::RegistrationActivity { .. },
+                AbstractActivity::Unknown
+            ]
+        ));
+    }
 }
@@ -1,6 +1,9 @@
+use crate::defs::{OAuthError, OAuthErrorCode};
+
 use hyper::{StatusCode, Uri};
 
 use std::fmt;
+use std::time::Duration;
 
 /// Result type returned by `Client`.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -9,16 +12,71 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug)]
 pub struct Error {
     kind: Kind,
-    url: Option<Uri>,
+    url: Option<Box<Uri>>,
 }
 impl Error {
-    pub(crate) fn server_error(status: StatusCode, resp: String, url: Uri) -> Error {
+    /// Creates an error from an unsuccessful response, classified by its status code.
+    pub(crate) fn server_error(
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        resp: String,
+        url: Uri,
+    ) -> Error {
+        let kind = match status {
+            StatusCode::UNAUTHORIZED => Kind::Unauthorized(resp),
+            StatusCode::FORBIDDEN => Kind::Forbidden(resp),
+            StatusCode::NOT_FOUND => Kind::NotFound(resp),
+            StatusCode::TOO_MANY_REQUESTS => Kind::RateLimited(retry_after, resp),
+            _ => Kind::ServerError(status, retry_after, resp),
+        };
+        Error {
+            kind,
+            url: Some(Box::new(url)),
+        }
+    }
+
+    /// Decodes the response of a failed `auth/token` request as OAuth error.
+    ///
+    /// Errors with a response which is not an OAuth error are returned unchanged.
+    pub(crate) fn into_oauth_error(self) -> Error {
+        let (status, resp) = match (self.status(), self.response()) {
+            (Some(status), Some(resp)) => (status, resp),
+            _ => return self,
+        };
+        match serde_json::from_str::<OAuthError>(resp) {
+            Ok(err) => Error {
+                kind: Kind::OAuth(status, err, resp.to_string()),
+                url: self.url,
+            },
+            Err(_) => self,
+        }
+    }
+
+    pub(crate) fn timeout(url: Uri) -> Error {
         Error {
-            kind: Kind::ServerError(status, resp),
-            url: Some(url),
+            kind: Kind::Timeout,
+            url: Some(Box::new(url)),
         }
     }
 
+    pub(crate) fn not_authenticated() -> Error {
+        Error {
+            kind: Kind::NotAuthenticated,
+            url: None,
+        }
+    }
+
+    /// Returns true if the server rejected the request with `401 Unauthorized`.
+    pub(crate) fn is_unauthorized(&self) -> bool {
+        self.status() == Some(StatusCode::UNAUTHORIZED)
+    }
+
+    /// Returns true if the `auth/token` endpoint rejected the credentials or the refresh token
+    /// with `invalid_grant`, i.e. the member has to log in again.
+    pub fn is_invalid_grant(&self) -> bool {
+        matches!(self.kind, Kind::OAuth(_, ref err, _) if err.code() == OAuthErrorCode::InvalidGrant)
+    }
+
     /// Returns error kind which is the cause of this error.
     pub fn kind(&self) -> &Kind {
         &self.kind
@@ -26,20 +84,86 @@ impl Error {
 
     /// Returns the url (if any) for which the error occurred.
     pub fn url(&self) -> Option<&Uri> {
-        self.url.as_ref()
+        self.url.as_deref()
+    }
+
+    /// Returns the status code, if the server responded with an error.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self.kind {
+            Kind::OAuth(status, _, _) | Kind::ServerError(status, _, _) => Some(status),
+            Kind::Unauthorized(_) => Some(StatusCode::UNAUTHORIZED),
+            Kind::Forbidden(_) => Some(StatusCode::FORBIDDEN),
+            Kind::NotFound(_) => Some(StatusCode::NOT_FOUND),
+            Kind::RateLimited(_, _) => Some(StatusCode::TOO_MANY_REQUESTS),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw response body, if the server responded with an error.
+    pub fn response(&self) -> Option<&str> {
+        match self.kind {
+            Kind::OAuth(_, _, ref resp)
+            | Kind::Unauthorized(ref resp)
+            | Kind::Forbidden(ref resp)
+            | Kind::NotFound(ref resp)
+            | Kind::RateLimited(_, ref resp)
+            | Kind::ServerError(_, _, ref resp) => Some(resp),
+            _ => None,
+        }
+    }
+
+    /// Returns how long to wait before retrying, if the server rate limited the
+    /// request or is unavailable, and sent a `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self.kind {
+            Kind::RateLimited(retry_after, _) | Kind::ServerError(_, retry_after, _) => retry_after,
+            _ => None,
+        }
     }
 }
 
 /// Different kinds of error which might occur.
+///
+/// Error responses of the server keep the raw response body.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Kind {
+    /// Error in the HTTP protocol.
     Http(hyper::Error),
+    /// Error while connecting to or communicating with the server.
     HttpClient(hyper_util::client::legacy::Error),
+    /// Invalid url.
     Uri(hyper::http::uri::InvalidUri),
+    /// Invalid base url or endpoint path.
+    Url(url::ParseError),
+    /// Invalid value of a header, e.g. the user agent.
+    InvalidHeader(hyper::header::InvalidHeaderValue),
+    /// Error while encoding a request or decoding a response.
     Json(serde_json::Error),
+    /// Invalid UTF-8 in the response.
     Utf8Error(std::str::Utf8Error),
+    /// Error while encoding the query parameters of a request.
     UrlEncoding(serde_url_params::Error),
-    ServerError(StatusCode, String /* response */),
+    /// Error while accessing a token store.
+    Io(std::io::Error),
+    /// The request did not complete within the configured timeout.
+    Timeout,
+    /// The `auth/token` endpoint rejected the request, e.g. with `invalid_grant`
+    /// for wrong credentials or an expired refresh token.
+    OAuth(StatusCode, OAuthError, String /* response */),
+    /// `401 Unauthorized`: the access token is missing, invalid or expired.
+    Unauthorized(String /* response */),
+    /// `403 Forbidden`: the authenticated member may not access the resource.
+    Forbidden(String /* response */),
+    /// `404 Not Found`: the requested resource (e.g. a film LID) does not exist.
+    NotFound(String /* response */),
+    /// `429 Too Many Requests` with the duration of the `Retry-After` header, if any.
+    RateLimited(Option<Duration>, String /* response */),
+    /// Any other unsuccessful status code, in particular `5xx` server errors, with
+    /// the duration of the `Retry-After` header, if any (e.g. for `503 Service Unavailable`).
+    ServerError(StatusCode, Option<Duration>, String /* response */),
+    /// The request requires an access token, but the client is not authenticated.
+    NotAuthenticated,
 }
 
 impl fmt::Display for Error {
@@ -52,12 +176,40 @@ impl fmt::Display for Error {
             Kind::Http(ref e) => fmt::Display::fmt(e, f),
             Kind::HttpClient(ref e) => fmt::Display::fmt(e, f),
             Kind::Uri(ref e) => fmt::Display::fmt(e, f),
+            Kind::Url(ref e) => fmt::Display::fmt(e, f),
+            Kind::InvalidHeader(ref e) => fmt::Display::fmt(e, f),
             Kind::Json(ref e) => fmt::Display::fmt(e, f),
             Kind::Utf8Error(ref e) => fmt::Display::fmt(e, f),
             Kind::UrlEncoding(ref e) => fmt::Display::fmt(e, f),
-            Kind::ServerError(ref code, ref resp) => {
+            Kind::Io(ref e) => fmt::Display::fmt(e, f),
+            Kind::Timeout => f.write_str("Request timed out"),
+            Kind::OAuth(ref code, ref err, _) => match err.error_description {
+                Some(ref description) => {
+                    write!(f, "OAuth Error: {}, {}: {}", code, err.error, description)
+                }
+                None => write!(f, "OAuth Error: {}, {}", code, err.error),
+            },
+            Kind::Unauthorized(ref resp) => write!(f, "Unauthorized, Response: {}", resp),
+            Kind::Forbidden(ref resp) => write!(f, "Forbidden, Response: {}", resp),
+            Kind::NotFound(ref resp) => write!(f, "Not Found, Response: {}", resp),
+            Kind::RateLimited(Some(retry_after), ref resp) => write!(
+                f,
+                "Rate Limited, Retry After: {}s, Response: {}",
+                retry_after.as_secs(),
+                resp
+            ),
+            Kind::RateLimited(None, ref resp) => write!(f, "Rate Limited, Response: {}", resp),
+            Kind::ServerError(ref code, Some(retry_after), ref resp) => write!(
+                f,
+                "Server Error: {}, Retry After: {}s, Response: {}",
+                code,
+                retry_after.as_secs(),
+                resp
+            ),
+            Kind::ServerError(ref code, None, ref resp) => {
                 write!(f, "Server Error: {}, Response: {}", code, resp)
             }
+            Kind::NotAuthenticated => f.write_str("Client is not authenticated"),
         }
     }
 }
@@ -73,6 +225,24 @@ impl From<hyper::http::uri::InvalidUri> for Error {
     }
 }
 
+impl From<url::ParseError> for Error {
+    fn from(err: url::ParseError) -> Self {
+        Self {
+            kind: Kind::Url(err),
+            url: None,
+        }
+    }
+}
+
+impl From<hyper::header::InvalidHeaderValue> for Error {
+    fn from(err: hyper::header::InvalidHeaderValue) -> Self {
+        Self {
+            kind: Kind::InvalidHeader(err),
+            url: None,
+        }
+    }
+}
+
 impl From<hyper::Error> for Error {
     fn from(err: hyper::Error) -> Self {
         Self {
@@ -118,6 +288,15 @@ impl From<serde_url_params::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self {
+            kind: Kind::Io(err),
+            url: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +306,63 @@ mod tests {
         fn is_sync_and_send<E: Sync + Send>() {}
         is_sync_and_send::<Error>();
     }
+
+    fn error(status: StatusCode, resp: &str) -> Error {
+        let url = "https://api.letterboxd.com/api/v0/film/xxxx"
+            .parse()
+            .unwrap();
+        Error::server_error(status, Some(Duration::from_secs(30)), resp.into(), url)
+    }
+
+    #[test]
+    fn server_errors_are_classified_by_status() {
+        let err = error(StatusCode::NOT_FOUND, "not found");
+        assert!(matches!(err.kind(), Kind::NotFound(_)));
+        assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+        assert_eq!(err.response(), Some("not found"));
+        assert_eq!(err.retry_after(), None);
+
+        let err = error(StatusCode::TOO_MANY_REQUESTS, "");
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(30)));
+
+        let err = error(StatusCode::UNAUTHORIZED, "");
+        assert!(err.is_unauthorized());
+
+        let err = error(StatusCode::BAD_GATEWAY, "");
+        assert!(matches!(
+            err.kind(),
+            Kind::ServerError(StatusCode::BAD_GATEWAY, _, _)
+        ));
+
+        let err = error(StatusCode::SERVICE_UNAVAILABLE, "");
+        assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn oauth_errors_are_decoded() {
+        let resp = r#"{"error":"invalid_grant","error_description":"Bad credentials"}"#;
+        let err = error(StatusCode::BAD_REQUEST, resp).into_oauth_error();
+        match err.kind() {
+            Kind::OAuth(StatusCode::BAD_REQUEST, err, _) => {
+                assert_eq!(err.error, "invalid_grant");
+                assert_eq!(err.code(), OAuthErrorCode::InvalidGrant);
+                assert_eq!(err.error_description.as_deref(), Some("Bad credentials"));
+            }
+            kind => panic!("unexpected error kind {:?}", kind),
+        }
+        assert!(err.is_invalid_grant());
+        assert_eq!(err.response(), Some(resp));
+
+        let resp = r#"{"error":"temporarily_unavailable"}"#;
+        let err = error(StatusCode::SERVICE_UNAVAILABLE, resp).into_oauth_error();
+        match err.kind() {
+            Kind::OAuth(_, err, _) => assert_eq!(err.code(), OAuthErrorCode::Other),
+            kind => panic!("unexpected error kind {:?}", kind),
+        }
+        assert!(!err.is_invalid_grant());
+
+        let err = error(StatusCode::BAD_GATEWAY, "<html>").into_oauth_error();
+        assert!(matches!(err.kind(), Kind::ServerError(_, _, _)));
+    }
 }
@@ -0,0 +1,142 @@
+//! Typed Letterboxd IDs (LIDs).
+//!
+//! Each kind of entity has its own ID type, so that e.g. the LID of a list
+//! cannot be passed to an endpoint expecting the LID of a film. The types are
+//! serialized as plain strings and can be created from a string with
+//! [`From`] or [`str::parse`].
+//!
+//! ```compile_fail
+//! async fn film(client: &letterboxd::Client) -> letterboxd::Result<()> {
+//!     let list_id = letterboxd::ListId::new("1fKte");
+//!     client.film(&list_id).await?;
+//!     Ok(())
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+macro_rules! lid {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Creates an ID from its LID.
+            pub fn new(lid: impl Into<String>) -> Self {
+                Self(lid.into())
+            }
+
+            /// Returns the LID as string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Converts the ID into its LID.
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::new(s))
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(lid: String) -> Self {
+                Self(lid)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(lid: &str) -> Self {
+                Self::new(lid)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+lid! {
+    /// LID of a film, e.g. `2a9q` for Fight Club.
+    FilmId
+}
+
+lid! {
+    /// LID of a list.
+    ListId
+}
+
+lid! {
+    /// LID of a member.
+    MemberId
+}
+
+lid! {
+    /// LID of a log entry, which is also the LID of its review.
+    LogEntryId
+}
+
+lid! {
+    /// LID of a contributor, i.e. a director, actor, etc.
+    ContributorId
+}
+
+lid! {
+    /// LID of a list or review comment.
+    CommentId
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lid_round_trip() {
+        let id: FilmId = "2a9q".parse().unwrap();
+        assert_eq!(id.to_string(), "2a9q");
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""2a9q""#);
+        let id: FilmId = serde_json::from_str(r#""2a9q""#).unwrap();
+        assert_eq!(id, FilmId::from("2a9q"));
+        assert_eq!(id, "2a9q");
+        assert_eq!(id.as_str(), "2a9q");
+
+        let req = crate::MembersRequest {
+            film: Some(id),
+            ..Default::default()
+        };
+        assert_eq!(serde_url_params::to_string(&req).unwrap(), "film=2a9q");
+    }
+}
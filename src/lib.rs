@@ -9,8 +9,17 @@
 //! * A client is always created from API key and secret. If auth token,
 //!   is provided, client calls will be authenticated. Client can be
 //!   created from username/password. A token can be set after client was created.
+//! * An expired token can be refreshed explicitly or, if enabled, automatically
+//!   by the client.
 //! * API key and secret can be created from default environment variables.
+//! * Base url, timeouts, headers and the HTTP connector can be configured
+//!   with a [ClientBuilder](struct.ClientBuilder.html).
+//! * Requests failing with a transient error can be retried, cf.
+//!   [RetryPolicy](struct.RetryPolicy.html), and throttled on the client side,
+//!   cf. [RateLimit](struct.RateLimit.html).
 //! * Except GET calls all methods include a path parameter.
+//! * Cursored endpoints can also be consumed as a stream of items with the
+//!   corresponding `*_stream` method, cf. [Paginator](struct.Paginator.html).
 //!
 //! Further, most of the [Client](struct.Client.html)'s methods take a request
 //! struct, which is then serialized to url encoded parameters, and return a
@@ -20,7 +29,14 @@
 //! Entities are identified in the API by Letterboxd ID (or LID), an
 //! alpha-numeric string value that is returned where appropriate. For films,
 //! lists and reviews, the LID can also be found through the Letterboxd website
-//! as the path portion of the entity’s shareable boxd.it link.
+//! as the path portion of the entity’s shareable boxd.it link. Each kind of
+//! LID has its own type, e.g. [FilmId](struct.FilmId.html) or
+//! [ListId](struct.ListId.html).
+//!
+//! Timestamps and dates are kept as ISO 8601 strings. With the `chrono`
+//! feature enabled, types with such fields additionally get accessors of the
+//! same name parsing them into `chrono` types, e.g.
+//! [LogEntry::when_created](struct.LogEntry.html#method.when_created).
 //!
 //! For more information, cf. API docs at <http://api-docs.letterboxd.com>.
 //!
@@ -53,23 +69,37 @@
 //!     let password = std::env::var("LETTERBOXD_PASSWORD").unwrap();
 //!
 //!     let client = letterboxd::Client::authenticate(api_key_pair, &username, &password).await?;
-//!     // token can be retrieved after authentication for e.g. caching it on disk
+//!     // token can be retrieved after authentication for e.g. caching it on disk,
+//!     // cf. `FileTokenStore`
 //!     println!("{:?}", client.token().unwrap());
 //!
 //!     let req = letterboxd::FilmRelationshipUpdateRequest {
 //!         watched: Some(true),
 //!         ..Default::default()
 //!     };
-//!     client.update_film_relationship("2a9q", &req).await?; // Fight Club
+//!     let fight_club = letterboxd::FilmId::new("2a9q");
+//!     client.update_film_relationship(&fight_club, &req).await?;
 //!
 //!     Ok(())
 //! }
 //! ```
 
 mod client;
+#[cfg(feature = "chrono")]
+mod datetime;
 mod defs;
 mod error;
+mod id;
+mod paginate;
+mod rate_limit;
+mod retry;
+mod token_store;
 
-pub use client::{ApiKeyPair, Client};
+pub use client::{ApiKeyPair, Client, ClientBuilder};
 pub use defs::*;
-pub use error::{Error, Result};
+pub use error::{Error, Kind, Result};
+pub use id::{CommentId, ContributorId, FilmId, ListId, LogEntryId, MemberId};
+pub use paginate::{PagedRequest, PagedResponse, Paginator};
+pub use rate_limit::RateLimit;
+pub use retry::RetryPolicy;
+pub use token_store::{FileTokenStore, MemoryTokenStore, TokenStore};
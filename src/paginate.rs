@@ -0,0 +1,227 @@
+use crate::client::Client;
+use crate::defs::{self, Cursor};
+use crate::error::Result;
+
+use futures_core::Stream;
+use serde::{de::DeserializeOwned, Serialize};
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Request of a cursored endpoint.
+pub trait PagedRequest: Serialize + Clone {
+    /// Sets the pagination cursor.
+    fn set_cursor(&mut self, cursor: Option<Cursor>);
+
+    /// Returns the number of items to include per page, if set.
+    fn per_page(&self) -> Option<usize>;
+
+    /// Sets the number of items to include per page.
+    fn set_per_page(&mut self, per_page: Option<usize>);
+}
+
+/// Response of a cursored endpoint containing a single page.
+pub trait PagedResponse: DeserializeOwned {
+    /// The type of the items in the page.
+    type Item;
+
+    /// Splits the response into the cursor to the next page and the items.
+    fn into_page(self) -> (Option<Cursor>, Vec<Self::Item>);
+}
+
+macro_rules! impl_paged_request {
+    ($($request:ty),* $(,)?) => {
+        $(
+            impl PagedRequest for $request {
+                fn set_cursor(&mut self, cursor: Option<Cursor>) {
+                    self.cursor = cursor;
+                }
+
+                fn per_page(&self) -> Option<usize> {
+                    self.per_page
+                }
+
+                fn set_per_page(&mut self, per_page: Option<usize>) {
+                    self.per_page = per_page;
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_paged_response {
+    ($($response:ty => $item:ty),* $(,)?) => {
+        $(
+            impl PagedResponse for $response {
+                type Item = $item;
+
+                fn into_page(self) -> (Option<Cursor>, Vec<Self::Item>) {
+                    (self.next, self.items)
+                }
+            }
+        )*
+    };
+}
+
+impl_paged_request!(
+    defs::ActivityRequest,
+    defs::CommentsRequest,
+    defs::FilmContributionsRequest,
+    defs::FilmsRequest,
+    defs::ListEntriesRequest,
+    defs::ListsRequest,
+    defs::LogEntriesRequest,
+    defs::MemberFilmRelationshipsRequest,
+    defs::MembersRequest,
+    defs::SearchRequest,
+    defs::WatchlistRequest,
+);
+
+impl_paged_response!(
+    defs::ActivityResponse => defs::AbstractActivity,
+    defs::FilmContributionsResponse => defs::FilmContribution,
+    defs::FilmsResponse => defs::FilmSummary,
+    defs::ListCommentsResponse => defs::ListComment,
+    defs::ListEntriesResponse => defs::ListEntry,
+    defs::ListsResponse => defs::ListSummary,
+    defs::LogEntriesResponse => defs::LogEntry,
+    defs::MemberFilmRelationshipsResponse => defs::MemberFilmRelationship,
+    defs::MembersResponse => defs::MemberSummary,
+    defs::ReviewCommentsResponse => defs::ReviewComment,
+    defs::SearchResponse => defs::AbstractSearchItem,
+);
+
+/// Maximum number of items per page supported by the API.
+const MAX_PER_PAGE: usize = 100;
+
+type PageFuture<'a, R> = Pin<Box<dyn Future<Output = Result<R>> + Send + 'a>>;
+
+/// Stream over the items of all pages of a cursored endpoint.
+///
+/// Pages are requested lazily one after the other, by following the ‘next’
+/// cursor of each page, until the last page is reached or the limit of items
+/// is exhausted. The stream ends after the first error.
+///
+/// Created by the `*_stream` methods of the [Client](struct.Client.html).
+pub struct Paginator<'a, Q, R: PagedResponse> {
+    client: &'a Client,
+    endpoint_path: String,
+    request: Q,
+    limit: Option<usize>,
+    items: VecDeque<R::Item>,
+    page: Option<PageFuture<'a, R>>,
+    done: bool,
+}
+
+impl<'a, Q, R> Paginator<'a, Q, R>
+where
+    Q: PagedRequest + Send + Sync + 'a,
+    R: PagedResponse + Send + 'static,
+{
+    pub(crate) fn new(client: &'a Client, endpoint_path: String, request: Q) -> Self {
+        Self {
+            client,
+            endpoint_path,
+            request,
+            limit: None,
+            items: VecDeque::new(),
+            page: None,
+            done: false,
+        }
+    }
+
+    /// Limits the total number of items returned by the stream.
+    ///
+    /// Unless the number of items per page is set, it is lowered to the limit,
+    /// so that no more items than necessary are requested.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        if self.request.per_page().is_none() {
+            self.request
+                .set_per_page(Some(limit.clamp(1, MAX_PER_PAGE)));
+        }
+        self
+    }
+
+    /// Sets the number of items to request per page (default is 20, maximum
+    /// is 100).
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        self.request.set_per_page(Some(per_page));
+        self
+    }
+
+    fn fetch_page(&self) -> PageFuture<'a, R> {
+        let client = self.client;
+        let endpoint_path = self.endpoint_path.clone();
+        let request = self.request.clone();
+        Box::pin(async move { client.get_with_query(&endpoint_path, &request).await })
+    }
+}
+
+// The page future is boxed and no other field is pinned.
+impl<Q, R: PagedResponse> Unpin for Paginator<'_, Q, R> {}
+
+impl<'a, Q, R> Stream for Paginator<'a, Q, R>
+where
+    Q: PagedRequest + Send + Sync + 'a,
+    R: PagedResponse + Send + 'static,
+{
+    type Item = Result<R::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.limit == Some(0) {
+                return Poll::Ready(None);
+            }
+            if let Some(item) = this.items.pop_front() {
+                if let Some(limit) = this.limit.as_mut() {
+                    *limit -= 1;
+                }
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            let page = match this.page.as_mut() {
+                Some(page) => page,
+                None => this.page.insert(this.fetch_page()),
+            };
+            let res = match page.as_mut().poll(cx) {
+                Poll::Ready(res) => res,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.page = None;
+
+            match res {
+                Ok(resp) => {
+                    let (next, items) = resp.into_page();
+                    // an empty page is treated as the last one to never loop endlessly
+                    this.done = next.is_none() || items.is_empty();
+                    this.request.set_cursor(next);
+                    this.items.extend(items);
+                }
+                Err(e) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+impl<Q: fmt::Debug, R: PagedResponse> fmt::Debug for Paginator<'_, Q, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Paginator")
+            .field("endpoint_path", &self.endpoint_path)
+            .field("request", &self.request)
+            .field("limit", &self.limit)
+            .field("buffered_items", &self.items.len())
+            .field("done", &self.done)
+            .finish()
+    }
+}
@@ -0,0 +1,194 @@
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Client-side limit of the request rate.
+///
+/// Requests are throttled by a token bucket which is refilled at the given rate and holds at
+/// most `burst` tokens. Optionally, the number of concurrent requests is capped as well.
+///
+/// When the server responds with `429 Too Many Requests`, the rate is halved and all requests
+/// are held back for the `Retry-After` duration. With each successful request, the rate recovers
+/// gradually up to the configured rate.
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    requests_per_second: f64,
+    burst: u32,
+    max_concurrent_requests: Option<usize>,
+}
+
+impl RateLimit {
+    /// Creates a new limit of the given number of requests per second and a burst of 1.
+    ///
+    /// Panics if `requests_per_second` is not positive.
+    pub fn new(requests_per_second: f64) -> Self {
+        assert!(
+            requests_per_second > 0.0,
+            "requests per second must be positive"
+        );
+        Self {
+            requests_per_second,
+            burst: 1,
+            max_concurrent_requests: None,
+        }
+    }
+
+    /// Sets the number of requests which can be sent at once after a period of inactivity.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Caps the number of requests in flight at the same time.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests.max(1));
+        self
+    }
+}
+
+/// Rate at most lowered to this fraction of the configured rate on `429 Too Many Requests`.
+const MIN_RATE_FRACTION: f64 = 1.0 / 16.0;
+/// Fraction of the configured rate recovered with each successful request.
+const RECOVERY_FRACTION: f64 = 1.0 / 16.0;
+
+/// Token bucket shared by all clones of a client.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+    concurrency: Option<Semaphore>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative if tokens are reserved by waiting requests.
+    tokens: f64,
+    /// Current refill rate in tokens per second.
+    rate: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        let bucket = Bucket {
+            tokens: limit.burst as f64,
+            rate: limit.requests_per_second,
+            updated: Instant::now(),
+        };
+        Self {
+            concurrency: limit.max_concurrent_requests.map(Semaphore::new),
+            limit,
+            bucket: Mutex::new(bucket),
+        }
+    }
+
+    /// Waits until a request may be sent.
+    ///
+    /// The returned permit must be held until the response is received.
+    pub(crate) async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        let permit = match self.concurrency {
+            Some(ref semaphore) => Some(semaphore.acquire().await.expect("semaphore closed")),
+            None => None,
+        };
+        let delay = self.reserve(Instant::now());
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        permit
+    }
+
+    /// Lowers the rate and holds back further requests after `429 Too Many Requests`.
+    pub(crate) fn rate_limited(&self, retry_after: Option<Duration>) {
+        self.rate_limited_at(Instant::now(), retry_after)
+    }
+
+    /// Recovers the rate after a request was not rate limited.
+    pub(crate) fn succeeded(&self) {
+        let mut bucket = self.bucket.lock().expect("poisoned rate limiter lock");
+        bucket.rate = (bucket.rate + self.limit.requests_per_second * RECOVERY_FRACTION)
+            .min(self.limit.requests_per_second);
+    }
+
+    /// Takes a token and returns how long to wait until it is available.
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut bucket = self.bucket.lock().expect("poisoned rate limiter lock");
+        self.refill(&mut bucket, now);
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / bucket.rate)
+        }
+    }
+
+    fn rate_limited_at(&self, now: Instant, retry_after: Option<Duration>) {
+        let mut bucket = self.bucket.lock().expect("poisoned rate limiter lock");
+        self.refill(&mut bucket, now);
+        bucket.rate = (bucket.rate / 2.0).max(self.limit.requests_per_second * MIN_RATE_FRACTION);
+        if let Some(retry_after) = retry_after {
+            // the next token becomes available only after `retry_after`
+            bucket.tokens = bucket.tokens.min(0.0) - retry_after.as_secs_f64() * bucket.rate;
+        }
+    }
+
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let elapsed = now.saturating_duration_since(bucket.updated);
+        bucket.tokens =
+            (bucket.tokens + elapsed.as_secs_f64() * bucket.rate).min(self.limit.burst as f64);
+        bucket.updated = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    fn assert_approx(actual: Duration, expected: Duration) {
+        let diff = actual.as_secs_f64() - expected.as_secs_f64();
+        assert!(diff.abs() < 1e-6, "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn token_bucket() {
+        let limiter = RateLimiter::new(RateLimit::new(10.0).burst(2));
+        let start = limiter.bucket.lock().unwrap().updated;
+
+        assert_approx(limiter.reserve(start), Duration::ZERO);
+        assert_approx(limiter.reserve(start), Duration::ZERO);
+        assert_approx(limiter.reserve(start), millis(100));
+        assert_approx(limiter.reserve(start), millis(200));
+
+        // refill does not exceed the burst
+        let later = start + Duration::from_secs(10);
+        assert_approx(limiter.reserve(later), Duration::ZERO);
+        assert_approx(limiter.reserve(later), Duration::ZERO);
+        assert_approx(limiter.reserve(later), millis(100));
+    }
+
+    #[test]
+    fn rate_limited_slows_down() {
+        let limiter = RateLimiter::new(RateLimit::new(10.0));
+        let start = limiter.bucket.lock().unwrap().updated;
+
+        limiter.rate_limited_at(start, Some(Duration::from_secs(1)));
+        // 1s retry after and the next token at half the rate
+        assert_approx(limiter.reserve(start), millis(1200));
+
+        let limiter = RateLimiter::new(RateLimit::new(10.0));
+        for _ in 0..10 {
+            limiter.rate_limited_at(start, None);
+        }
+        assert_approx(limiter.reserve(start), Duration::ZERO);
+        assert_approx(limiter.reserve(start), millis(1600));
+
+        for _ in 0..100 {
+            limiter.succeeded();
+        }
+        assert_eq!(limiter.bucket.lock().unwrap().rate, 10.0);
+    }
+}
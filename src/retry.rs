@@ -0,0 +1,146 @@
+use crate::error::{Error, Kind};
+
+use hyper::Method;
+
+use std::time::Duration;
+
+/// Policy for retrying requests which failed with a transient error.
+///
+/// Only requests with an idempotent method (`GET`, `PUT`, `DELETE`, ...) are retried, when they
+/// failed with a connection error, a timeout, `429 Too Many Requests` or a `5xx` server error.
+///
+/// Between attempts, the client waits for the duration of the `Retry-After` header if the server
+/// sent one, and otherwise for an exponentially growing backoff with random jitter.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a new policy making at most `max_attempts` attempts per request (including the
+    /// first one).
+    ///
+    /// The backoff starts at 500ms and is capped at 30s.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the backoff before the first retry, which is doubled for each further retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the maximum backoff between two attempts.
+    ///
+    /// A longer `Retry-After` duration requested by the server is still honored.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Returns the maximum number of attempts per request.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns true if the request should be attempted again after the given failed attempt
+    /// (starting at 1).
+    pub(crate) fn should_retry(&self, method: &Method, err: &Error, attempt: u32) -> bool {
+        attempt < self.max_attempts && is_idempotent(method) && is_transient(err)
+    }
+
+    /// Returns the duration to wait after the given failed attempt (starting at 1).
+    pub(crate) fn backoff(&self, err: &Error, attempt: u32) -> Duration {
+        if let Some(retry_after) = err.retry_after() {
+            return retry_after;
+        }
+        let exp = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exp)
+            .min(self.max_backoff);
+        // full jitter: uniformly distributed in [0, backoff]
+        let random = (uuid::Uuid::new_v4().as_u128() >> 64) as u64;
+        backoff.mul_f64(random as f64 / u64::MAX as f64)
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+fn is_transient(err: &Error) -> bool {
+    match err.kind() {
+        Kind::Http(_) | Kind::HttpClient(_) | Kind::Timeout | Kind::RateLimited(_, _) => true,
+        Kind::ServerError(status, _, _) => status.is_server_error(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hyper::StatusCode;
+
+    fn error(status: StatusCode, retry_after: Option<Duration>) -> Error {
+        let url = "https://api.letterboxd.com/api/v0/films".parse().unwrap();
+        Error::server_error(status, retry_after, String::new(), url)
+    }
+
+    #[test]
+    fn retries_transient_errors_of_idempotent_requests() {
+        let policy = RetryPolicy::new(3);
+        let unavailable = error(StatusCode::SERVICE_UNAVAILABLE, None);
+        assert!(policy.should_retry(&Method::GET, &unavailable, 1));
+        assert!(policy.should_retry(&Method::GET, &unavailable, 2));
+        assert!(!policy.should_retry(&Method::GET, &unavailable, 3));
+        assert!(!policy.should_retry(&Method::POST, &unavailable, 1));
+        assert!(!policy.should_retry(&Method::PATCH, &unavailable, 1));
+
+        let rate_limited = error(StatusCode::TOO_MANY_REQUESTS, None);
+        assert!(policy.should_retry(&Method::DELETE, &rate_limited, 1));
+
+        let not_found = error(StatusCode::NOT_FOUND, None);
+        assert!(!policy.should_retry(&Method::GET, &not_found, 1));
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_and_honors_retry_after() {
+        let policy = RetryPolicy::new(10)
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1));
+        let err = error(StatusCode::BAD_GATEWAY, None);
+        for _ in 0..100 {
+            assert!(policy.backoff(&err, 1) <= Duration::from_millis(100));
+            assert!(policy.backoff(&err, 3) <= Duration::from_millis(400));
+            assert!(policy.backoff(&err, 40) <= Duration::from_secs(1));
+        }
+
+        let err = error(StatusCode::TOO_MANY_REQUESTS, Some(Duration::from_secs(5)));
+        assert_eq!(policy.backoff(&err, 1), Duration::from_secs(5));
+
+        let err = error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            Some(Duration::from_secs(5)),
+        );
+        assert!(policy.should_retry(&Method::GET, &err, 1));
+        assert_eq!(policy.backoff(&err, 1), Duration::from_secs(5));
+    }
+}
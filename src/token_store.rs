@@ -0,0 +1,209 @@
+use crate::defs::AccessToken;
+use crate::error::Result;
+
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Persistent storage of the access token.
+///
+/// A client with a token store loads its token from the store when it is
+/// created, and saves the token to the store whenever it is set or refreshed.
+/// Setting the token to `None` clears the store.
+pub trait TokenStore: fmt::Debug + Send + Sync {
+    /// Loads the stored token, if any.
+    fn load(&self) -> Result<Option<AccessToken>>;
+
+    /// Saves the token replacing any previously stored token.
+    fn save(&self, token: &AccessToken) -> Result<()>;
+
+    /// Removes the stored token.
+    fn clear(&self) -> Result<()>;
+}
+
+impl<T: TokenStore + ?Sized> TokenStore for Arc<T> {
+    fn load(&self) -> Result<Option<AccessToken>> {
+        (**self).load()
+    }
+
+    fn save(&self, token: &AccessToken) -> Result<()> {
+        (**self).save(token)
+    }
+
+    fn clear(&self) -> Result<()> {
+        (**self).clear()
+    }
+}
+
+/// Token store which keeps the token in memory.
+///
+/// Useful to share a token between several clients in the same process.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<AccessToken>>,
+}
+
+impl MemoryTokenStore {
+    /// Creates a new empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Option<AccessToken>> {
+        Ok(self.token.lock().expect("poisoned token lock").clone())
+    }
+
+    fn save(&self, token: &AccessToken) -> Result<()> {
+        *self.token.lock().expect("poisoned token lock") = Some(token.clone());
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<()> {
+        *self.token.lock().expect("poisoned token lock") = None;
+        Ok(())
+    }
+}
+
+/// Token store which keeps the token as JSON in a file.
+///
+/// A missing file is treated as an empty store.
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    /// Creates a new store backed by the file at the given path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the file backing the store.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns a new unique path for a temporary file next to the file, so that concurrent
+    /// writers, e.g. several processes sharing the store, never write to the same file.
+    fn tmp_path(&self) -> PathBuf {
+        let mut file_name = self.path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(format!(".{}.tmp", uuid::Uuid::new_v4().simple()));
+        self.path.with_file_name(file_name)
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Result<Option<AccessToken>> {
+        match std::fs::read(&self.path) {
+            Ok(content) => Ok(Some(serde_json::from_slice(&content)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, token: &AccessToken) -> Result<()> {
+        // write to a temporary file first, so that the file is never left half written
+        let tmp_path = self.tmp_path();
+        let content = serde_json::to_vec(token)?;
+        let res = create_private(&tmp_path)
+            .and_then(|mut file| {
+                file.write_all(&content)?;
+                file.sync_all()
+            })
+            .and_then(|()| std::fs::rename(&tmp_path, &self.path));
+        if res.is_err() {
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+        Ok(res?)
+    }
+
+    fn clear(&self) -> Result<()> {
+        match std::fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Creates a new file which is only readable and writable by the owner (on unix).
+///
+/// Fails if the file already exists, so that the permissions of an existing file are never
+/// reused.
+fn create_private(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token() -> AccessToken {
+        AccessToken {
+            access_token: "access".into(),
+            token_type: "bearer".into(),
+            refresh_token: "refresh".into(),
+            expires_in: 3600,
+            issued_at: 1_000_000,
+        }
+    }
+
+    fn check_store(store: &dyn TokenStore) {
+        assert!(store.load().unwrap().is_none());
+
+        store.save(&token()).unwrap();
+        let loaded = store.load().unwrap().unwrap();
+        assert_eq!(loaded.access_token, "access");
+        assert_eq!(loaded.issued_at, 1_000_000);
+
+        store.clear().unwrap();
+        assert!(store.load().unwrap().is_none());
+        store.clear().unwrap();
+    }
+
+    #[test]
+    fn memory_token_store() {
+        check_store(&MemoryTokenStore::new());
+    }
+
+    #[test]
+    fn file_token_store() {
+        let path = std::env::temp_dir().join(format!("letterboxd-{}.json", uuid::Uuid::new_v4()));
+        check_store(&FileTokenStore::new(path));
+    }
+
+    #[test]
+    fn file_token_store_tmp_path() {
+        let store = FileTokenStore::new("/var/lib/tokens.tmp");
+        let tmp_path = store.tmp_path();
+        assert_eq!(tmp_path.parent(), Some(Path::new("/var/lib")));
+        let file_name = tmp_path.file_name().unwrap().to_str().unwrap();
+        assert!(file_name.starts_with("tokens.tmp.") && file_name.ends_with(".tmp"));
+        assert_ne!(tmp_path, store.path());
+        assert_ne!(tmp_path, store.tmp_path());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn file_token_store_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!("letterboxd-{}.json", uuid::Uuid::new_v4()));
+        let store = FileTokenStore::new(&path);
+        store.save(&token()).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        store.clear().unwrap();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -12,16 +12,18 @@ async fn init() -> letterboxd::Result<letterboxd::Client> {
 #[tokio::test]
 async fn film_relationship() -> letterboxd::Result<()> {
     let client = init().await?;
-    const FIGHT_CLUB_ID: &str = "2a9q";
+    let fight_club_id = letterboxd::FilmId::new("2a9q");
 
-    let film_relationship = client.film_relationship(FIGHT_CLUB_ID).await?; // Fight Club
+    let film_relationship = client.film_relationship(&fight_club_id).await?; // Fight Club
     println!("{:?}", film_relationship);
 
     let req = letterboxd::FilmRelationshipUpdateRequest {
         watched: Some(true),
         ..Default::default()
     };
-    let res = client.update_film_relationship(FIGHT_CLUB_ID, &req).await?;
+    let res = client
+        .update_film_relationship(&fight_club_id, &req)
+        .await?;
     println!("{:?}", res);
 
     let req = letterboxd::MemberFilmRelationshipsRequest {
@@ -29,7 +31,7 @@ async fn film_relationship() -> letterboxd::Result<()> {
         ..Default::default()
     };
     let film_relationship_members = client
-        .film_relationship_members(FIGHT_CLUB_ID, &req)
+        .film_relationship_members(&fight_club_id, &req)
         .await?;
     println!("{:?}", film_relationship_members);
 
@@ -58,8 +60,8 @@ async fn list() -> letterboxd::Result<()> {
 
     let req = letterboxd::ListUpdateRequest {
         entries: vec![
-            letterboxd::ListUpdateEntry::new(String::from("2a9q")), // Fight Club
-            letterboxd::ListUpdateEntry::new(String::from("bPI")),  // Melancholia
+            letterboxd::ListUpdateEntry::new("2a9q".into()), // Fight Club
+            letterboxd::ListUpdateEntry::new("bPI".into()),  // Melancholia
         ],
         ..letterboxd::ListUpdateRequest::new(String::from(LIST_NAME))
     };
@@ -71,3 +73,222 @@ async fn list() -> letterboxd::Result<()> {
 
     Ok(())
 }
+
+#[ignore]
+#[tokio::test]
+async fn log_entry() -> letterboxd::Result<()> {
+    let client = init().await?;
+
+    // 1. create a new diary entry
+    // 2. get the log entry
+    // 3. patch the log entry
+    // 4. delete the log entry
+
+    let req = letterboxd::LogEntryCreationRequest {
+        diary_details: Some(letterboxd::LogEntryCreationRequestDiaryDetails {
+            diary_date: String::from("2019-01-01"),
+            rewatch: false,
+        }),
+        rating: Some(4.5),
+        ..letterboxd::LogEntryCreationRequest::new("2a9q".into()) // Fight Club
+    };
+    let log_entry = client.create_log_entry(&req).await?;
+    assert_eq!(log_entry.film.id, "2a9q");
+
+    let log_entry = client.log_entry(&log_entry.id).await?;
+    assert_eq!(log_entry.rating, Some(4.5));
+
+    let req = letterboxd::LogEntryUpdateRequest {
+        rating: Some(None),
+        like: Some(true),
+        ..Default::default()
+    };
+    let resp = client.update_log_entry(&log_entry.id, &req).await?;
+    assert_eq!(resp.data.rating, None);
+    assert!(resp.data.like);
+
+    client.delete_log_entry(&resp.data.id).await?;
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn me() -> letterboxd::Result<()> {
+    let client = init().await?;
+
+    let account = client.me().await?;
+    println!("{:?}", account);
+
+    let req = letterboxd::MemberSettingsUpdateRequest {
+        location: account.member.location.clone(),
+        ..Default::default()
+    };
+    let resp = client.update_me(&req).await?;
+    assert_eq!(resp.data.member.id, account.member.id);
+    for message in resp.messages {
+        if let letterboxd::MemberSettingsUpdateMessage::Error { code, title } = message {
+            panic!("unexpected error {:?}: {}", code, title);
+        }
+    }
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn refresh_token() -> letterboxd::Result<()> {
+    let client = init().await?;
+    let token = client.token().unwrap();
+
+    client.refresh_token().await?;
+    let refreshed_token = client.token().unwrap();
+    assert_ne!(token.access_token, refreshed_token.access_token);
+
+    client.me().await?;
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn token_store() -> letterboxd::Result<()> {
+    let path = env::temp_dir().join("letterboxd-token.json");
+
+    let mut client = init().await?;
+    client.set_token_store(letterboxd::FileTokenStore::new(&path))?;
+
+    let api_key_pair = letterboxd::ApiKeyPair::from_env().unwrap();
+    let client =
+        letterboxd::Client::with_token_store(api_key_pair, letterboxd::FileTokenStore::new(&path))?;
+    assert!(client.is_authenticated());
+    client.me().await?;
+
+    client.set_token(None)?;
+    assert!(!path.exists());
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn list_comment() -> letterboxd::Result<()> {
+    let client = init().await?;
+
+    // 1. create a new list
+    // 2. comment on the list
+    // 3. edit and delete the comment
+    // 4. delete the list
+
+    let resp = client
+        .create_list(&letterboxd::ListCreationRequest::new(String::from(
+            "list with comments",
+        )))
+        .await?;
+    let list_id = resp.data.id;
+
+    let comment = client
+        .create_list_comment(
+            &list_id,
+            &letterboxd::CommentCreationRequest::new(String::from("first")),
+        )
+        .await?;
+
+    let resp = client
+        .update_comment(
+            &comment.id,
+            &letterboxd::CommentUpdateRequest::new(String::from("edited")),
+        )
+        .await?;
+    println!("{:?}", resp);
+
+    let resp = client.list_comments(&list_id, &Default::default()).await?;
+    assert_eq!(resp.items.len(), 1);
+
+    client.delete_comment(&comment.id).await?;
+    client.delete_list(&list_id).await?;
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn list_relationship() -> letterboxd::Result<()> {
+    let client = init().await?;
+
+    let resp = client
+        .create_list(&letterboxd::ListCreationRequest::new(String::from(
+            "list relationship",
+        )))
+        .await?;
+    let list_id = resp.data.id;
+
+    let relationship = client.list_relationship(&list_id).await?;
+    println!("{:?}", relationship);
+
+    let req = letterboxd::ListRelationshipUpdateRequest {
+        subscribed: Some(false),
+        ..Default::default()
+    };
+    let resp = client.update_list_relationship(&list_id, &req).await?;
+    println!("{:?}", resp);
+    assert!(!resp.data.subscribed);
+
+    client.delete_list(&list_id).await?;
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn review_relationship() -> letterboxd::Result<()> {
+    let client = init().await?;
+
+    let req = letterboxd::LogEntryCreationRequest {
+        review: Some(letterboxd::LogEntryCreationRequestReview {
+            text: String::from("The first rule of Fight Club is..."),
+            contains_spoilers: false,
+            share: Vec::new(),
+        }),
+        ..letterboxd::LogEntryCreationRequest::new("2a9q".into()) // Fight Club
+    };
+    let log_entry = client.create_log_entry(&req).await?;
+
+    let relationship = client.review_relationship(&log_entry.id).await?;
+    println!("{:?}", relationship);
+
+    let req = letterboxd::ReviewRelationshipUpdateRequest {
+        subscribed: Some(false),
+        ..Default::default()
+    };
+    let resp = client
+        .update_review_relationship(&log_entry.id, &req)
+        .await?;
+    println!("{:?}", resp);
+    assert!(!resp.data.subscribed);
+
+    client.delete_log_entry(&log_entry.id).await?;
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn member_relationship() -> letterboxd::Result<()> {
+    let client = init().await?;
+    let dave_id = letterboxd::MemberId::new("11Ht");
+
+    let relationship = client.member_relationship(&dave_id).await?;
+    println!("{:?}", relationship);
+
+    let req = letterboxd::MemberRelationshipUpdateRequest {
+        following: Some(relationship.following),
+        ..Default::default()
+    };
+    let resp = client.update_member_relationship(&dave_id, &req).await?;
+    println!("{:?}", resp);
+    let updated = resp.into_result().expect("update rejected");
+    assert_eq!(updated.following, relationship.following);
+
+    Ok(())
+}
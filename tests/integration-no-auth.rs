@@ -42,18 +42,27 @@ async fn film_genres() -> letterboxd::Result<()> {
 #[tokio::test]
 async fn film() -> letterboxd::Result<()> {
     let client = init();
-    let resp = client.film("2a9q").await?; // Fight Club
+    let resp = client.film(&"2a9q".into()).await?; // Fight Club
     println!("{:?}", resp);
     assert_eq!(resp.name, "Fight Club");
 
     Ok(())
 }
 
+#[ignore]
+#[tokio::test]
+async fn film_not_found() {
+    let client = init();
+    let err = client.film(&"xxxxxxxx".into()).await.unwrap_err();
+    println!("{:?}", err);
+    assert!(matches!(err.kind(), letterboxd::Kind::NotFound(_)));
+}
+
 #[ignore]
 #[tokio::test]
 async fn film_availability() -> letterboxd::Result<()> {
     let client = init();
-    let resp = client.film_availability("2a9q").await?; // Fight Club
+    let resp = client.film_availability(&"2a9q".into()).await?; // Fight Club
     println!("{:?}", resp);
 
     Ok(())
@@ -63,7 +72,7 @@ async fn film_availability() -> letterboxd::Result<()> {
 #[tokio::test]
 async fn film_statistics() -> letterboxd::Result<()> {
     let client = init();
-    let resp = client.film_statistics("2a9q").await?; // Fight Club
+    let resp = client.film_statistics(&"2a9q".into()).await?; // Fight Club
     println!("{:?}", resp);
 
     Ok(())
@@ -73,7 +82,7 @@ async fn film_statistics() -> letterboxd::Result<()> {
 #[tokio::test]
 async fn list() -> letterboxd::Result<()> {
     let client = init();
-    let resp = client.list("1fKte").await?; // Collection
+    let resp = client.list(&"1fKte".into()).await?; // Collection
     println!("{:?}", resp);
     assert_eq!(resp.name, "Collection");
 
@@ -85,7 +94,7 @@ async fn list() -> letterboxd::Result<()> {
 async fn list_entries() -> letterboxd::Result<()> {
     let client = init();
     let req = letterboxd::ListEntriesRequest::default();
-    let resp = client.list_entries("1fKte", &req).await?; // Collection
+    let resp = client.list_entries(&"1fKte".into(), &req).await?; // Collection
     println!("{:?}", resp);
 
     Ok(())
@@ -170,3 +179,184 @@ async fn films_request_language() -> letterboxd::Result<()> {
 
     Ok(())
 }
+
+#[ignore]
+#[tokio::test]
+async fn log_entries() -> letterboxd::Result<()> {
+    let client = init();
+    let req = letterboxd::LogEntriesRequest {
+        per_page: Some(1),
+        film: Some("2a9q".into()), // Fight Club
+        where_logentry_status: vec![letterboxd::LogEntryStatus::HasReview],
+        ..Default::default()
+    };
+    let resp = client.log_entries(&req).await?;
+    println!("{:?}", resp);
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn member() -> letterboxd::Result<()> {
+    let client = init();
+    let resp = client.member(&"11Ht".into()).await?; // Dave Vis
+    println!("{:?}", resp);
+    assert_eq!(resp.username, "dave");
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn members() -> letterboxd::Result<()> {
+    let client = init();
+    let req = letterboxd::MembersRequest {
+        per_page: Some(1),
+        member: Some("11Ht".into()), // Dave Vis
+        member_relationship: Some(letterboxd::MembersRequestRelationship::IsFollowedBy),
+        ..Default::default()
+    };
+    let resp = client.members(&req).await?;
+    println!("{:?}", resp);
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn member_activity() -> letterboxd::Result<()> {
+    let client = init();
+    let req = letterboxd::ActivityRequest {
+        per_page: Some(10),
+        ..Default::default()
+    };
+    let resp = client.member_activity(&"11Ht".into(), &req).await?; // Dave Vis
+    println!("{:?}", resp);
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn member_watchlist() -> letterboxd::Result<()> {
+    let client = init();
+    let req = letterboxd::WatchlistRequest {
+        per_page: Some(1),
+        sort: Some(letterboxd::WatchlistSort::FilmName),
+        ..Default::default()
+    };
+    let resp = client.member_watchlist(&"11Ht".into(), &req).await?; // Dave Vis
+    println!("{:?}", resp);
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn films_stream() -> letterboxd::Result<()> {
+    use futures_util::TryStreamExt;
+
+    let client = init();
+    let films: Vec<_> = client
+        .films_stream(letterboxd::FilmsRequest::default())
+        .per_page(2)
+        .limit(5)
+        .try_collect()
+        .await?;
+    assert_eq!(films.len(), 5);
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn list_statistics() -> letterboxd::Result<()> {
+    let client = init();
+    let resp = client.list_statistics(&"1fKte".into()).await?; // Collection
+    println!("{:?}", resp);
+    assert_eq!(resp.list.id, "1fKte");
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn member_statistics() -> letterboxd::Result<()> {
+    let client = init();
+    let resp = client.member_statistics(&"11Ht".into()).await?; // Dave Vis
+    println!("{:?}", resp);
+    assert_eq!(resp.member.id, "11Ht");
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn member_tags() -> letterboxd::Result<()> {
+    let client = init();
+    let req = letterboxd::MemberTagsRequest::default();
+    let resp = client.member_list_tags(&"11Ht".into(), &req).await?; // Dave Vis
+    println!("{:?}", resp);
+    let resp = client.member_log_entry_tags(&"11Ht".into(), &req).await?;
+    println!("{:?}", resp);
+    let resp = client.member_review_tags(&"11Ht".into(), &req).await?;
+    println!("{:?}", resp);
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn contributor() -> letterboxd::Result<()> {
+    let client = init();
+    let film = client.film(&"2a9q".into()).await?; // Fight Club
+    let director = film
+        .contributions
+        .iter()
+        .find(|c| {
+            matches!(
+                c.contribution_type,
+                Some(letterboxd::ContributionType::Director)
+            )
+        })
+        .and_then(|c| c.contributors.first())
+        .expect("missing director");
+
+    let resp = client.contributor(&director.id).await?;
+    println!("{:?}", resp);
+
+    let req = letterboxd::FilmContributionsRequest {
+        per_page: Some(10),
+        contribution_type: Some(letterboxd::ContributionType::Director),
+        ..Default::default()
+    };
+    let resp = client.contributor_contributions(&director.id, &req).await?;
+    println!("{:?}", resp);
+    assert!(resp.items.iter().any(|c| c.film.id == "2a9q"));
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn films_autocomplete() -> letterboxd::Result<()> {
+    let client = init();
+    let resp = client.films_autocomplete("fight cl", Some(5)).await?;
+    println!("{:?}", resp);
+    assert!(resp.items.iter().any(|film| film.name == "Fight Club"));
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn check_username() -> letterboxd::Result<()> {
+    let client = init();
+    let result = client.check_username("dave").await?;
+    assert_eq!(result, letterboxd::UsernameCheckResult::NotAvailable);
+    let result = client.check_username("d").await?;
+    assert_eq!(result, letterboxd::UsernameCheckResult::TooShort);
+
+    Ok(())
+}
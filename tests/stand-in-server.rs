@@ -0,0 +1,437 @@
+use futures_util::StreamExt;
+use hyper::header::{HeaderName, HeaderValue};
+use hyper_util::client::legacy::connect::HttpConnector;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+use std::time::Duration;
+
+/// Serves one connection per given raw HTTP response and returns the raw requests.
+async fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}/api/v0", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            let head_len = loop {
+                if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break pos + 4;
+                }
+                let n = stream.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break request.len();
+                }
+                request.extend_from_slice(&buf[..n]);
+            };
+            let head = String::from_utf8_lossy(&request[..head_len]).to_lowercase();
+            let content_length: usize = head
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map_or(0, |value| value.trim().parse().unwrap());
+            while request.len() < head_len + content_length {
+                let n = stream.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+            requests.push(String::from_utf8(request).unwrap());
+        }
+        requests
+    });
+    (base_url, handle)
+}
+
+async fn serve_once(response: impl Into<String>) -> (String, JoinHandle<Vec<String>>) {
+    serve(vec![response.into()]).await
+}
+
+fn json_response(body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nconnection: close\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
+fn genres_response() -> String {
+    json_response(r#"{"items":[{"id":"8G","name":"Action"}]}"#)
+}
+
+/// Page of activities of a type unknown to the crate, which keeps the items short.
+fn activity_page(next: Option<&str>, len: usize) -> String {
+    let next = next.map_or("null".to_string(), |next| format!("\"{}\"", next));
+    let items = vec![r#"{"type":"SomeFutureActivity"}"#; len].join(",");
+    json_response(&format!(r#"{{"next":{},"items":[{}]}}"#, next, items))
+}
+
+fn builder(base_url: &str) -> letterboxd::ClientBuilder {
+    let api_key_pair = letterboxd::ApiKeyPair::new("key".into(), "secret".into());
+    letterboxd::Client::builder(api_key_pair).base_url(base_url)
+}
+
+#[tokio::test]
+async fn base_url_and_headers() -> letterboxd::Result<()> {
+    let (base_url, server) = serve_once(genres_response()).await;
+
+    let client = builder(&base_url)
+        .user_agent("stand-in-test/1.0")
+        .default_header(
+            HeaderName::from_static("x-test"),
+            HeaderValue::from_static("stand-in"),
+        )
+        // overridden by the client
+        .default_header(
+            hyper::header::ACCEPT_ENCODING,
+            HeaderValue::from_static("gzip"),
+        )
+        .connector(HttpConnector::new())
+        .build()?;
+    let resp = client.film_genres().await?;
+    assert_eq!(resp.items[0].name, "Action");
+
+    let request = server.await.unwrap()[0].to_lowercase();
+    assert!(request.starts_with("get /api/v0/films/genres?apikey=key&nonce="));
+    assert!(request.contains("user-agent: stand-in-test/1.0\r\n"));
+    assert!(request.contains("x-test: stand-in\r\n"));
+    assert!(request.contains("accept-encoding: application/json\r\n"));
+    assert!(!request.contains("gzip"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn not_found() {
+    let (base_url, _server) =
+        serve_once("HTTP/1.1 404 Not Found\r\ncontent-length: 9\r\n\r\nnot found").await;
+
+    let client = builder(&base_url).build().unwrap();
+    let err = client
+        .film(&letterboxd::FilmId::new("xxxx"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), letterboxd::Kind::NotFound(_)));
+    assert_eq!(err.response(), Some("not found"));
+}
+
+#[tokio::test]
+async fn rate_limited() {
+    let (base_url, _server) =
+        serve_once("HTTP/1.1 429 Too Many Requests\r\nretry-after: 7\r\ncontent-length: 0\r\n\r\n")
+            .await;
+
+    let client = builder(&base_url).build().unwrap();
+    let err = client.film_genres().await.unwrap_err();
+    assert!(matches!(err.kind(), letterboxd::Kind::RateLimited(_, _)));
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(7)));
+}
+
+#[tokio::test]
+async fn report_film() -> letterboxd::Result<()> {
+    let (base_url, server) =
+        serve_once("HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").await;
+
+    let client = builder(&base_url).build()?;
+    let req = letterboxd::ReportFilmRequest {
+        message: Some(String::from("Duplicate of 2a9q")),
+        ..letterboxd::ReportFilmRequest::new(letterboxd::ReportFilmReason::Duplicate)
+    };
+    client.report_film(&"xxxx".into(), &req).await?;
+
+    let request = &server.await.unwrap()[0];
+    assert!(request.starts_with("POST /api/v0/film/xxxx/report?"));
+    assert!(request.ends_with(r#"{"reason":"Duplicate","message":"Duplicate of 2a9q"}"#));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn check_username() -> letterboxd::Result<()> {
+    let body = r#"{"result":"TooShort"}"#;
+    let (base_url, server) = serve_once(format!(
+        "HTTP/1.1 200 OK\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    ))
+    .await;
+
+    let client = builder(&base_url).build()?;
+    let result = client.check_username("d").await?;
+    assert_eq!(result, letterboxd::UsernameCheckResult::TooShort);
+
+    let request = &server.await.unwrap()[0];
+    assert!(request.starts_with("GET /api/v0/auth/username-check?username=d&"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pronouns_are_cached() -> letterboxd::Result<()> {
+    let body = r#"{"items":[{
+        "id": "1",
+        "label": "They / their",
+        "subjectPronoun": "they",
+        "objectPronoun": "them",
+        "possessiveAdjective": "their",
+        "possessivePronoun": "theirs",
+        "reflexive": "themselves"
+    }]}"#;
+    // only a single request is served
+    let (base_url, _server) = serve_once(format!(
+        "HTTP/1.1 200 OK\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    ))
+    .await;
+
+    let client = builder(&base_url).timeout(Duration::from_secs(1)).build()?;
+    let resp = client.pronouns().await?;
+    assert_eq!(resp.items[0].subject_pronoun, "they");
+    let resp = client.clone().pronouns().await?;
+    assert_eq!(resp.items[0].id, "1");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn paginator_follows_cursors() -> letterboxd::Result<()> {
+    let (base_url, server) = serve(vec![
+        activity_page(Some("c1"), 2),
+        activity_page(Some("c2"), 1),
+        // an empty page ends the stream even if it has a cursor
+        activity_page(Some("c3"), 0),
+    ])
+    .await;
+
+    let client = builder(&base_url).build()?;
+    let member = letterboxd::MemberId::new("11Ht");
+    let items: Vec<_> = client
+        .member_activity_stream(&member, Default::default())
+        .per_page(2)
+        .collect()
+        .await;
+    assert_eq!(items.len(), 3);
+    for item in items {
+        assert!(matches!(item?, letterboxd::AbstractActivity::Unknown));
+    }
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("GET /api/v0/member/11Ht/activity?perPage=2&apikey="));
+    assert!(requests[1].starts_with("GET /api/v0/member/11Ht/activity?cursor=c1&perPage=2&"));
+    assert!(requests[2].starts_with("GET /api/v0/member/11Ht/activity?cursor=c2&perPage=2&"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn paginator_limit() -> letterboxd::Result<()> {
+    // a third page is never requested
+    let (base_url, server) = serve(vec![
+        activity_page(Some("c1"), 2),
+        activity_page(Some("c2"), 2),
+    ])
+    .await;
+
+    let client = builder(&base_url).build()?;
+    let member = letterboxd::MemberId::new("11Ht");
+    let items: Vec<_> = client
+        .member_activity_stream(&member, Default::default())
+        .limit(3)
+        .collect()
+        .await;
+    assert_eq!(items.len(), 3);
+    assert!(items.iter().all(Result::is_ok));
+
+    // the page size is lowered to the limit
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("GET /api/v0/member/11Ht/activity?perPage=3&"));
+    assert!(requests[1].starts_with("GET /api/v0/member/11Ht/activity?cursor=c1&perPage=3&"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn paginator_ends_after_error() -> letterboxd::Result<()> {
+    let (base_url, _server) = serve(vec![
+        activity_page(Some("c1"), 2),
+        "HTTP/1.1 500 Internal Server Error\r\nconnection: close\r\ncontent-length: 0\r\n\r\n"
+            .into(),
+        activity_page(None, 2),
+    ])
+    .await;
+
+    let client = builder(&base_url).build()?;
+    let member = letterboxd::MemberId::new("11Ht");
+    let items: Vec<_> = client
+        .member_activity_stream(&member, Default::default())
+        .collect()
+        .await;
+    assert_eq!(items.len(), 3);
+    assert!(items[0].is_ok() && items[1].is_ok());
+    let err = items[2].as_ref().unwrap_err();
+    assert!(matches!(err.kind(), letterboxd::Kind::ServerError(_, _, _)));
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct FailingTokenStore;
+
+impl letterboxd::TokenStore for FailingTokenStore {
+    fn load(&self) -> letterboxd::Result<Option<letterboxd::AccessToken>> {
+        Ok(None)
+    }
+
+    fn save(&self, _token: &letterboxd::AccessToken) -> letterboxd::Result<()> {
+        Err(std::io::Error::other("disk full").into())
+    }
+
+    fn clear(&self) -> letterboxd::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn refreshed_token_is_kept_if_store_fails() -> letterboxd::Result<()> {
+    let (base_url, server) = serve_once(json_response(
+        r#"{"access_token":"new","token_type":"bearer","refresh_token":"refresh-2","expires_in":3600}"#,
+    ))
+    .await;
+
+    let client = builder(&base_url).token_store(FailingTokenStore).build()?;
+    let token = letterboxd::AccessToken {
+        access_token: "old".into(),
+        refresh_token: "refresh-1".into(),
+        ..Default::default()
+    };
+    assert!(client.set_token(Some(token)).is_err());
+
+    let err = client.refresh_token().await.unwrap_err();
+    assert!(matches!(err.kind(), letterboxd::Kind::Io(_)));
+    // the used up refresh token is not lost
+    let token = client.token().unwrap();
+    assert_eq!(token.access_token, "new");
+    assert_eq!(token.refresh_token, "refresh-2");
+
+    let request = &server.await.unwrap()[0];
+    assert!(request.ends_with("grant_type=refresh_token&refresh_token=refresh-1"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stored_token_is_used_instead_of_refreshing() -> letterboxd::Result<()> {
+    // no `auth/token` response is served
+    let (base_url, server) = serve_once(genres_response()).await;
+
+    let store = std::sync::Arc::new(letterboxd::MemoryTokenStore::new());
+    let expired = letterboxd::AccessToken {
+        access_token: "expired".into(),
+        refresh_token: "used-up".into(),
+        expires_in: 3600,
+        issued_at: 0,
+        ..Default::default()
+    };
+    let client = builder(&base_url)
+        .token(expired)
+        .token_store(store.clone())
+        .auto_refresh(true)
+        .build()?;
+
+    // another process sharing the store refreshed the token in the meantime
+    let refreshed = letterboxd::AccessToken {
+        access_token: "refreshed".into(),
+        refresh_token: "refresh".into(),
+        expires_in: 3600,
+        issued_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        ..Default::default()
+    };
+    letterboxd::TokenStore::save(&store, &refreshed)?;
+
+    client.film_genres().await?;
+    assert_eq!(client.token().unwrap().access_token, "refreshed");
+    let request = server.await.unwrap()[0].to_lowercase();
+    assert!(request.contains("authorization: bearer refreshed\r\n"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry() -> letterboxd::Result<()> {
+    let unavailable =
+        "HTTP/1.1 503 Service Unavailable\r\nconnection: close\r\ncontent-length: 0\r\n\r\n";
+    let (base_url, server) = serve(vec![
+        unavailable.into(),
+        unavailable.into(),
+        genres_response(),
+    ])
+    .await;
+
+    let policy = letterboxd::RetryPolicy::new(3).initial_backoff(Duration::from_millis(10));
+    let client = builder(&base_url).retry_policy(policy).build()?;
+    let resp = client.film_genres().await?;
+    assert_eq!(resp.items[0].name, "Action");
+
+    // each attempt is signed with a fresh nonce
+    let requests = server.await.unwrap();
+    let nonces: Vec<_> = requests
+        .iter()
+        .map(|request| request.split("nonce=").nth(1).unwrap().split('&').next())
+        .collect();
+    assert_eq!(nonces.len(), 3);
+    assert_ne!(nonces[0], nonces[1]);
+    assert_ne!(nonces[1], nonces[2]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limit_is_shared_by_clones() -> letterboxd::Result<()> {
+    let (base_url, _server) = serve(vec![genres_response(), genres_response()]).await;
+
+    let client = builder(&base_url)
+        .rate_limit(letterboxd::RateLimit::new(5.0).max_concurrent_requests(1))
+        .build()?;
+    let clone = client.clone();
+
+    let start = std::time::Instant::now();
+    client.film_genres().await?;
+    clone.film_genres().await?;
+    assert!(start.elapsed() >= Duration::from_millis(200));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn timeout() {
+    // accept the connection, but never respond
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}/api/v0/", listener.local_addr().unwrap());
+    let _server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        drop(stream);
+    });
+
+    let client = builder(&base_url)
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let err = client.film_genres().await.unwrap_err();
+    assert!(matches!(err.kind(), letterboxd::Kind::Timeout));
+}
+
+#[test]
+fn invalid_base_url() {
+    let err = builder("not a url").build().unwrap_err();
+    assert!(matches!(err.kind(), letterboxd::Kind::Url(_)));
+}